@@ -0,0 +1,99 @@
+use std::time::Duration;
+
+use crate::parse::ParseError;
+
+// Stats summarises repeated measurements of a single phase
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    // new expects at least one sample, median of an even count is the mean of the middle two
+    pub fn new(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+        Self {
+            min: sorted[0],
+            median,
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+// Bench holds timings of every phase ("parse", "part1", "part2") over all runs
+#[derive(Debug, Clone)]
+pub struct Bench {
+    pub day: u8,
+    pub runs: usize,
+    pub phases: Vec<(String, Stats)>,
+}
+
+// bench solves the input runs times, returns None for unknown days
+pub fn bench(
+    day: u8,
+    txt: &str,
+    part: Option<u8>,
+    runs: usize,
+) -> Option<Result<Bench, ParseError>> {
+    let mut samples: Vec<(String, Vec<Duration>)> = Vec::new();
+    for _ in 0..runs.max(1) {
+        let solved = match crate::run(day, txt, part)? {
+            Ok(solved) => solved,
+            Err(e) => return Some(Err(e)),
+        };
+        let mut phases = vec![("parse".to_string(), solved.parse)];
+        for p in solved.parts {
+            phases.push((format!("part{}", p.part), p.duration));
+        }
+        if samples.is_empty() {
+            samples = phases
+                .iter()
+                .map(|(name, _)| (name.clone(), Vec::new()))
+                .collect();
+        }
+        for (i, (_, duration)) in phases.into_iter().enumerate() {
+            samples[i].1.push(duration);
+        }
+    }
+    let phases = samples
+        .into_iter()
+        .map(|(name, durations)| (name, Stats::new(&durations)))
+        .collect();
+    return Some(Ok(Bench {
+        day,
+        runs: runs.max(1),
+        phases,
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_stats() {
+        let ms = Duration::from_millis;
+        let s = Stats::new(&[ms(5), ms(1), ms(3)]);
+        assert_eq!((ms(1), ms(3), ms(5)), (s.min, s.median, s.max));
+        let s = Stats::new(&[ms(4), ms(1), ms(2), ms(8)]);
+        assert_eq!((ms(1), ms(3), ms(8)), (s.min, s.median, s.max));
+    }
+
+    #[test]
+    fn check_bench() {
+        let b = bench(1, "3 4\n4 3\n", None, 3).unwrap().unwrap();
+        assert_eq!(3, b.runs);
+        let names: Vec<&str> = b.phases.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(vec!["parse", "part1", "part2"], names);
+        assert!(bench(42, "", None, 1).is_none());
+    }
+}
@@ -0,0 +1,329 @@
+use std::{error::Error, fmt, io, io::BufRead, str::FromStr};
+
+use crate::{
+    parse::{Line, ParseError},
+    Answer, Solution,
+};
+
+// ReadError tells whether the lists could not be read or did not look like two columns
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(e) => write!(f, "{e}"),
+            ReadError::Parse(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Error for ReadError {}
+
+impl From<io::Error> for ReadError {
+    fn from(e: io::Error) -> Self {
+        ReadError::Io(e)
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(e: ParseError) -> Self {
+        ReadError::Parse(e)
+    }
+}
+
+// Value is a number the lists can hold, every metric is computed in 128 bits
+pub trait Value: FromStr + Copy + Ord + Into<i128> {}
+
+impl<V: FromStr + Copy + Ord + Into<i128>> Value for V {}
+
+// read_columns reads columns of numbers line by line reusing a single buffer,
+// so only the numbers are kept in memory; every column comes out sorted.
+// Without the expected number of columns the first line decides it,
+// an input without numbers gives two empty columns.
+pub fn read_columns<V: Value>(
+    mut reader: impl BufRead,
+    columns: Option<usize>,
+) -> Result<Vec<Vec<V>>, ReadError> {
+    let mut lists: Vec<Vec<V>> = Vec::new();
+    let mut buf = String::new();
+    let mut no = 0;
+    loop {
+        buf.clear();
+        if reader.read_line(&mut buf)? == 0 {
+            break;
+        }
+        no += 1;
+        let line = Line {
+            day: Day1::DAY,
+            no,
+            text: buf.trim_end_matches(['\n', '\r']),
+        };
+        let numbers: Vec<&str> = line.text.split_ascii_whitespace().collect();
+        // empty lines are skipped
+        if numbers.is_empty() {
+            continue;
+        }
+        let n = match columns {
+            Some(n) => n,
+            None if lists.is_empty() => numbers.len().max(2),
+            None => lists.len(),
+        };
+        if numbers.len() < n {
+            let expected = match n {
+                2 => "two numbers".to_string(),
+                _ => format!("{n} numbers"),
+            };
+            return Err(line.error_at_end(&expected).into());
+        }
+        if numbers.len() > n {
+            return Err(line.error(numbers[n], "end of line").into());
+        }
+        lists.resize_with(n, Vec::new);
+        for i in 0..n {
+            lists[i].push(line.number(numbers[i])?);
+        }
+    }
+    if lists.is_empty() {
+        lists.resize_with(columns.unwrap_or(2), Vec::new);
+    }
+    for list in lists.iter_mut() {
+        list.sort_unstable();
+    }
+    return Ok(lists);
+}
+
+// Overflow tells which metric got too big for 64 bits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow {
+    pub metric: Metric,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} does not fit in 64 bits", self.metric.name())
+    }
+}
+
+impl Error for Overflow {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    // sum of distances between the n-th smallest numbers of both lists
+    L1,
+    // sum of squared distances between the n-th smallest numbers of both lists
+    L2,
+    // sum of left numbers weighted by how many times they appear in the right list
+    Similarity,
+    // number of (left, right) pairs holding the same number
+    Matches,
+}
+
+impl Metric {
+    pub const ALL: [Metric; 4] = [Metric::L1, Metric::L2, Metric::Similarity, Metric::Matches];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Metric::L1 => "l1",
+            Metric::L2 => "l2",
+            Metric::Similarity => "similarity",
+            Metric::Matches => "matches",
+        }
+    }
+
+    // measure compares two sorted lists, every step is checked so nothing wraps silently
+    pub fn measure<V: Value>(self, left: &[V], right: &[V]) -> Result<i64, Overflow> {
+        let sum = match self {
+            Metric::L1 => {
+                pairs(left, right).try_fold(0i128, |s, (a, b)| s.checked_add((a - b).abs()))
+            }
+            Metric::L2 => pairs(left, right).try_fold(0i128, |s, (a, b)| {
+                s.checked_add((a - b).checked_mul(a - b)?)
+            }),
+            Metric::Similarity => runs(left, right).try_fold(0i128, |s, (v, l, r)| {
+                s.checked_add(v.checked_mul(l)?.checked_mul(r)?)
+            }),
+            Metric::Matches => {
+                runs(left, right).try_fold(0i128, |s, (_, l, r)| s.checked_add(l.checked_mul(r)?))
+            }
+        };
+        sum.and_then(|s| i64::try_from(s).ok())
+            .ok_or(Overflow { metric: self })
+    }
+
+    // matrix measures every pair of columns, row i holds column i compared to all the others
+    pub fn matrix<V: Value>(self, columns: &Vec<Vec<V>>) -> Vec<Vec<Result<i64, Overflow>>> {
+        let mut matrix = Vec::new();
+        for i in 0..columns.len() {
+            let mut row = Vec::new();
+            for j in 0..columns.len() {
+                row.push(self.measure(&columns[i], &columns[j]));
+            }
+            matrix.push(row);
+        }
+        return matrix;
+    }
+}
+
+impl FromStr for Metric {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Metric::ALL
+            .into_iter()
+            .find(|m| m.name() == s)
+            .ok_or_else(|| format!("unknown metric {s:?}"))
+    }
+}
+
+fn pairs<'a, V: Value>(left: &'a [V], right: &'a [V]) -> impl Iterator<Item = (i128, i128)> + 'a {
+    left.iter()
+        .zip(right)
+        .map(|(a, b)| ((*a).into(), (*b).into()))
+}
+
+// runs merges two sorted lists yielding every common value with its count in both of them
+fn runs<'a, V: Value>(
+    left: &'a [V],
+    right: &'a [V],
+) -> impl Iterator<Item = (i128, i128, i128)> + 'a {
+    let (mut i, mut j) = (0, 0);
+    std::iter::from_fn(move || {
+        while i < left.len() && j < right.len() {
+            let v = left[i];
+            if v < right[j] {
+                i += 1;
+            } else if v > right[j] {
+                j += 1;
+            } else {
+                let (si, sj) = (i, j);
+                while i < left.len() && left[i] == v {
+                    i += 1;
+                }
+                while j < right.len() && right[j] == v {
+                    j += 1;
+                }
+                return Some((v.into(), (i - si) as i128, (j - sj) as i128));
+            }
+        }
+        None
+    })
+}
+
+// answer turns an overflow into an unsolved part, diagnostics tell why
+fn answer(measured: Result<i64, Overflow>) -> Answer {
+    match measured {
+        Ok(n) => n.into(),
+        Err(_) => Answer::Unsolved,
+    }
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(txt: &str) -> Result<Self::Input, ParseError> {
+        match read_columns(txt.as_bytes(), Some(2)) {
+            Ok(lists) => Ok(lists),
+            Err(ReadError::Parse(e)) => Err(e),
+            Err(ReadError::Io(e)) => unreachable!("reading from memory failed: {e}"),
+        }
+    }
+
+    fn part1(lists: &Self::Input) -> Answer {
+        answer(Metric::L1.measure(&lists[0], &lists[1]))
+    }
+
+    fn part2(lists: &Self::Input) -> Answer {
+        answer(Metric::Similarity.measure(&lists[0], &lists[1]))
+    }
+
+    fn diagnostics(lists: &Self::Input, part: u8) -> Vec<(&'static str, String)> {
+        let metric = if part == 1 {
+            Metric::L1
+        } else {
+            Metric::Similarity
+        };
+        match metric.measure(&lists[0], &lists[1]) {
+            Ok(_) => Vec::new(),
+            Err(e) => vec![("overflow", e.to_string())],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_metrics() {
+        let lists: Vec<Vec<i64>> =
+            read_columns(include_str!("test_input.txt").as_bytes(), Some(2)).unwrap();
+        let measured: Vec<Result<i64, Overflow>> = Metric::ALL
+            .iter()
+            .map(|m| m.measure(&lists[0], &lists[1]))
+            .collect();
+        assert_eq!(vec![Ok(11), Ok(35), Ok(31), Ok(10)], measured);
+        assert_eq!(Ok(Metric::L2), "l2".parse());
+        assert!("l3".parse::<Metric>().is_err());
+    }
+
+    #[test]
+    fn check_read_columns() {
+        let columns: Vec<Vec<u64>> = read_columns("3 1 2\n\n1 2 3\n".as_bytes(), None).unwrap();
+        assert_eq!(vec![vec![1, 3], vec![1, 2], vec![2, 3]], columns);
+        let empty: Vec<Vec<u64>> = read_columns("\n".as_bytes(), None).unwrap();
+        assert_eq!(vec![Vec::<u64>::new(); 2], empty);
+        let lists = Day1::parse("").unwrap();
+        assert_eq!(Answer::Num(0), Day1::part1(&lists));
+        assert_eq!(Answer::Num(0), Day1::part2(&lists));
+        let errors = [
+            ("1 2\n3\n", None, (2, 2)),
+            ("1 2\n3 4 5\n", None, (2, 5)),
+            ("1 2 3\n", Some(2), (1, 5)),
+            ("1 -2\n", None, (1, 3)),
+        ];
+        for (txt, columns, at) in errors {
+            match read_columns::<u64>(txt.as_bytes(), columns) {
+                Err(ReadError::Parse(e)) => assert_eq!(at, (e.line, e.column), "{txt:?}"),
+                other => panic!("unexpected {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn check_overflow() {
+        let (left, right) = (vec![0, u64::MAX], vec![1, u64::MAX]);
+        assert_eq!(Ok(1), Metric::L1.measure(&left, &right));
+        assert_eq!(Ok(1), Metric::Matches.measure(&left, &right));
+        let overflow = Err(Overflow {
+            metric: Metric::Similarity,
+        });
+        assert_eq!(overflow, Metric::Similarity.measure(&left, &right));
+        let (left, right) = (vec![0, 0], vec![u64::MAX, u64::MAX]);
+        assert!(Metric::L1.measure(&left, &right).is_err());
+        assert!(Metric::L2.measure(&left, &right).is_err());
+        let lists = vec![vec![i64::MAX, i64::MAX], vec![i64::MAX, i64::MAX]];
+        assert_eq!(Answer::Unsolved, Day1::part2(&lists));
+        assert_eq!(
+            vec![("overflow", "similarity does not fit in 64 bits".to_string())],
+            Day1::diagnostics(&lists, 2)
+        );
+    }
+
+    #[test]
+    fn check_matrix() {
+        let columns = vec![vec![1, 2, 3], vec![1, 2, 3], vec![2, 2, 5]];
+        let matrix = Metric::L1.matrix(&columns);
+        assert_eq!(vec![Ok(0), Ok(0), Ok(3)], matrix[0]);
+        assert_eq!(vec![Ok(3), Ok(3), Ok(0)], matrix[2]);
+        let matrix = Metric::Similarity.matrix(&columns);
+        assert_eq!(vec![Ok(6), Ok(6), Ok(4)], matrix[0]);
+        assert_eq!(vec![Ok(4), Ok(4), Ok(13)], matrix[2]);
+    }
+}
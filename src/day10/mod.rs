@@ -0,0 +1,118 @@
+use std::collections::HashSet;
+
+use crate::{grid::Grid, parse::ParseError, Answer, Solution};
+
+#[derive(Debug, PartialEq, Hash, Eq, Clone)]
+pub struct Point {
+    r: i32,
+    c: i32,
+    h: i32,
+}
+
+fn get_paths(map: &Grid<i32>, start: &Point) -> Vec<Vec<Point>> {
+    let mut paths: Vec<Vec<Point>> = Vec::new();
+    if start.h == 9 {
+        return vec![vec![start.clone()]];
+    }
+    for (_, p) in map.neighbours4((start.r, start.c)) {
+        if map[p] - start.h != 1 {
+            continue;
+        }
+        let next = Point {
+            r: p.0,
+            c: p.1,
+            h: start.h + 1,
+        };
+        for path in &mut get_paths(map, &next) {
+            let mut p = vec![start.clone()];
+            p.append(path);
+            paths.push(p);
+        }
+    }
+    return paths;
+}
+
+fn load_input(txt: &str) -> Result<(Grid<i32>, Vec<Point>), ParseError> {
+    // impassable `.` gets negative height
+    let map = Grid::parse(Day10::DAY, txt, "a height or `.`", |c| match c {
+        '0'..='9' | '.' => Some(c as i32 - '0' as i32),
+        _ => None,
+    })?;
+    let entrances = map
+        .iter()
+        .filter(|(_, h)| **h == 0)
+        .map(|((r, c), _)| Point { r, c, h: 0 })
+        .collect();
+    return Ok((map, entrances));
+}
+
+fn part1(map: &Grid<i32>, entrances: &Vec<Point>) -> usize {
+    let mut score: usize = 0;
+    for entrance in entrances {
+        let mut peaks: HashSet<Point> = HashSet::new();
+        get_paths(map, entrance).iter().for_each(|x| {
+            peaks.insert(x.last().unwrap().clone());
+        });
+        score += peaks.len()
+    }
+    return score;
+}
+
+fn part2(map: &Grid<i32>, entrances: &Vec<Point>) -> usize {
+    entrances
+        .iter()
+        .fold(0, |acc, x| acc + get_paths(map, x).len())
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = (Grid<i32>, Vec<Point>);
+
+    fn parse(txt: &str) -> Result<Self::Input, ParseError> {
+        load_input(txt)
+    }
+
+    fn part1((map, entrances): &Self::Input) -> Answer {
+        part1(map, entrances).into()
+    }
+
+    fn part2((map, entrances): &Self::Input) -> Answer {
+        part2(map, entrances).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn check1() {
+        let (map, entrances) = load_input(include_str!("test_input.txt")).unwrap();
+        println!("{:?}\n{:?}", map, entrances);
+        let paths = get_paths(&map, &entrances[0]);
+        for path in paths {
+            for r in 0..map.height() as i32 {
+                for c in 0..map.width() as i32 {
+                    if let Some(p) = path.iter().find(|x| x.c == c && x.r == r) {
+                        print!("{}", p.h);
+                    } else {
+                        print!(".");
+                    }
+                }
+                println!();
+            }
+
+            for p in path {
+                print!("{} {} ({}) -> ", p.r, p.c, p.h);
+            }
+            println!();
+        }
+        assert_eq!(36, part1(&map, &entrances));
+    }
+    #[test]
+    fn check2() {
+        let (map, entrances) = load_input(include_str!("test_input.txt")).unwrap();
+        assert_eq!(81, part2(&map, &entrances))
+    }
+}
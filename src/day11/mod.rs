@@ -0,0 +1,200 @@
+use std::{collections::HashMap, thread};
+
+use crate::{
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
+fn load_input(txt: &str) -> Result<Vec<u64>, ParseError> {
+    let mut lines = parse::lines(Day11::DAY, txt);
+    match (lines.next(), lines.next()) {
+        (None, _) => Err(parse::missing(Day11::DAY, txt, "stones")),
+        (Some(_), Some(extra)) => Err(extra.error(extra.text, "end of input")),
+        (Some(line), None) => line.text.split(' ').map(|x| line.number(x)).collect(),
+    }
+}
+
+// this one is correct, but brute-forces it
+#[allow(dead_code)]
+fn multicount(mut stones: Vec<u64>, times: usize) -> usize {
+    let mut handles: Vec<_> = Vec::new();
+    blink(&mut stones);
+    blink(&mut stones);
+    for s in stones {
+        let h = thread::spawn(move || count_stones(vec![s], times - 2));
+        handles.push(h);
+    }
+    let mut count = 0;
+    for h in handles {
+        count += h.join().unwrap();
+    }
+    return count;
+}
+fn count_stones(mut stones: Vec<u64>, times: usize) -> usize {
+    for i in 0..times {
+        blink(&mut stones);
+        let l = stones.len();
+        if l > 5000000 {
+            return count_stones(stones[..l / 2].into(), times - i - 1)
+                + count_stones(stones[l / 2..].into(), times - i - 1);
+        }
+    }
+    return stones.len();
+}
+
+fn blink(stones: &mut Vec<u64>) {
+    let l = stones.len();
+    for i in 0..l {
+        if stones[i] == 0 {
+            stones[i] = 1;
+            continue;
+        }
+        let pow = stones[i].ilog10() + 1;
+        if pow.is_multiple_of(2) {
+            let d = 10u64.pow(pow / 2);
+            let v = stones[i];
+            stones[i] = v / d;
+            stones.push(v % d);
+            continue;
+        }
+        stones[i] *= 2024;
+    }
+}
+
+fn do_stone_things(stone: u64, tips: &mut HashMap<(u64, usize), usize>, times: usize) -> usize {
+    if let Some(r) = tips.get(&(stone, times)) {
+        return *r;
+    }
+    let mut s = stone;
+
+    let mut dups = 0;
+    for i in 0..times {
+        if s == 0 {
+            s = 1;
+            continue;
+        }
+        let pow = s.ilog10() + 1;
+        if pow.is_multiple_of(2) {
+            let d = 10u64.pow(pow / 2);
+            dups += 1 + do_stone_things(s % d, tips, times - i - 1);
+            s = s / d;
+            continue;
+        }
+        s *= 2024;
+    }
+    tips.insert((stone, times), dups);
+    return dups;
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Vec<u64>;
+
+    fn parse(txt: &str) -> Result<Self::Input, ParseError> {
+        load_input(txt)
+    }
+
+    fn part1(stones: &Self::Input) -> Answer {
+        let mut tips: HashMap<_, _> = HashMap::new();
+        stones
+            .iter()
+            .fold(stones.len(), |acc, x| {
+                acc + do_stone_things(*x, &mut tips, 25)
+            })
+            .into()
+    }
+
+    fn part2(stones: &Self::Input) -> Answer {
+        let mut tips: HashMap<_, _> = HashMap::new();
+        stones
+            .iter()
+            .fold(stones.len(), |acc, x| {
+                acc + do_stone_things(*x, &mut tips, 75)
+            })
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use super::*;
+    use crate::differential::{cross_check, shrink_num, shrink_vec};
+
+    #[test]
+    fn check() {
+        let mut tips: HashMap<_, _> = HashMap::new();
+        let s = [125, 17]
+            .iter()
+            .fold(2, |acc, x| acc + do_stone_things(*x, &mut tips, 25));
+        assert_eq!(55312, s);
+    }
+
+    #[test]
+    fn check_negative() {
+        let e = load_input("125 -5").unwrap_err();
+        assert_eq!(((1, 5), "-5"), ((e.line, e.column), e.found.as_str()));
+    }
+
+    fn memoized(stones: &Vec<u64>, times: usize) -> usize {
+        let mut tips: HashMap<_, _> = HashMap::new();
+        stones.iter().fold(stones.len(), |acc, x| {
+            acc + do_stone_things(*x, &mut tips, times)
+        })
+    }
+
+    fn shrink_stones((stones, times): &(Vec<u64>, usize)) -> Vec<(Vec<u64>, usize)> {
+        let mut smaller: Vec<_> = shrink_vec(stones)
+            .into_iter()
+            .map(|s| (s, *times))
+            .collect();
+        for i in 0..stones.len() {
+            for n in shrink_num(stones[i] as i64) {
+                let mut s = stones.clone();
+                s[i] = n as u64;
+                smaller.push((s, *times));
+            }
+        }
+        // multicount blinks twice before splitting the work
+        if *times > 2 {
+            smaller.push((stones.clone(), times - 1));
+        }
+        return smaller;
+    }
+
+    fn random_stones(rng: &mut rand::rngs::StdRng) -> (Vec<u64>, usize) {
+        let stones = (0..rng.gen_range(1..4))
+            .map(|_| rng.gen_range(0..100000))
+            .collect();
+        (stones, rng.gen_range(2..16))
+    }
+
+    #[test]
+    fn check_count_vs_memoized() {
+        let r = cross_check(
+            200,
+            11,
+            random_stones,
+            shrink_stones,
+            |(stones, times)| count_stones(stones.clone(), *times),
+            |(stones, times)| memoized(stones, *times),
+        );
+        r.unwrap_or_else(|m| panic!("{m}"));
+    }
+
+    #[test]
+    fn check_multicount_vs_memoized() {
+        let r = cross_check(
+            50,
+            11,
+            random_stones,
+            shrink_stones,
+            |(stones, times)| multicount(stones.clone(), *times),
+            |(stones, times)| memoized(stones, *times),
+        );
+        r.unwrap_or_else(|m| panic!("{m}"));
+    }
+}
@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+
+use crate::{
+    grid::{Dir, Grid, Pos},
+    parse::ParseError,
+    Answer, Solution,
+};
+
+#[derive(Debug, Clone)]
+pub struct Plot {
+    region_id: i32,
+    plant: char,
+    r: i32,
+    c: i32,
+    fences: Vec<Dir>,
+}
+
+fn get_regions(map: &Grid<Plot>) -> HashMap<i32, Vec<&Plot>> {
+    let mut regions: HashMap<i32, Vec<&Plot>> = HashMap::new();
+    for (_, plot) in map.iter() {
+        match regions.get_mut(&plot.region_id) {
+            None => {
+                regions.insert(plot.region_id, vec![plot]);
+            }
+            Some(plots) => {
+                plots.push(plot);
+            }
+        };
+    }
+    return regions;
+}
+
+fn find_fence(p: &Plot, region: &Vec<&Plot>, d: &Dir, fd: &Dir) -> bool {
+    let n = d.step((p.r, p.c));
+    region
+        .iter()
+        .any(|x| x.r == n.0 && x.c == n.1 && x.fences.contains(fd))
+}
+
+fn count_sides(region: &Vec<&Plot>) -> usize {
+    let mut angles = 0;
+    for p in region {
+        if p.fences.contains(&Dir::Up) && p.fences.contains(&Dir::Right) {
+            angles += 1;
+        }
+        if p.fences.contains(&Dir::Up) && p.fences.contains(&Dir::Left) {
+            angles += 1;
+        }
+        if p.fences.contains(&Dir::Down) && p.fences.contains(&Dir::Right) {
+            angles += 1;
+        }
+        if p.fences.contains(&Dir::Down) && p.fences.contains(&Dir::Left) {
+            angles += 1;
+        }
+        if find_fence(p, region, &Dir::Up, &Dir::Right)
+            && find_fence(p, region, &Dir::Right, &Dir::Up)
+        {
+            angles += 1;
+        }
+        if find_fence(p, region, &Dir::Up, &Dir::Left)
+            && find_fence(p, region, &Dir::Left, &Dir::Up)
+        {
+            angles += 1
+        }
+        if find_fence(p, region, &Dir::Down, &Dir::Right)
+            && find_fence(p, region, &Dir::Right, &Dir::Down)
+        {
+            angles += 1
+        }
+        if find_fence(p, region, &Dir::Down, &Dir::Left)
+            && find_fence(p, region, &Dir::Left, &Dir::Down)
+        {
+            angles += 1
+        }
+    }
+    return angles;
+}
+
+fn get_price(map: &Grid<Plot>) -> usize {
+    let regions = get_regions(map);
+    regions.iter().fold(0, |acc, (_id, region)| {
+        acc + region.len() * region.iter().fold(0, |acc, plot| acc + plot.fences.len())
+    })
+}
+
+fn get_discounted_price(map: &Grid<Plot>) -> usize {
+    let regions = get_regions(map);
+    regions.iter().fold(0, |acc, (_, region)| {
+        acc + region.len() * count_sides(region)
+    })
+}
+
+fn load_input(txt: &str) -> Result<Grid<Plot>, ParseError> {
+    let plants = Grid::parse(Day12::DAY, txt, "a plant", Some)?;
+    let plots = plants
+        .iter()
+        .map(|((r, c), x)| Plot {
+            region_id: -1,
+            plant: *x,
+            r,
+            c,
+            fences: Vec::new(),
+        })
+        .collect();
+    return Ok(Grid::from_vec(plants.width(), plants.height(), plots));
+}
+
+fn lookup(map: &mut Grid<Plot>, p: Pos) {
+    for d in Dir::ALL {
+        match map.neighbour(p, d) {
+            None => map[p].fences.push(d),
+            Some(n) => {
+                if map[n].plant == map[p].plant {
+                    if map[n].region_id == -1 {
+                        map[n].region_id = map[p].region_id;
+                        lookup(map, n);
+                    }
+                } else {
+                    map[p].fences.push(d);
+                }
+            }
+        }
+    }
+}
+
+fn analyze(map: &mut Grid<Plot>) {
+    let mut next_id = 0;
+
+    for p in map.positions() {
+        if map[p].region_id != -1 {
+            continue;
+        }
+        map[p].region_id = next_id;
+        next_id += 1;
+        lookup(map, p);
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Grid<Plot>;
+
+    fn parse(txt: &str) -> Result<Self::Input, ParseError> {
+        let mut map = load_input(txt)?;
+        analyze(&mut map);
+        return Ok(map);
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        get_price(map).into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        get_discounted_price(map).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dump(map: &Grid<Plot>) {
+        let regions = get_regions(map);
+        for (region, plots) in regions.iter() {
+            println!(
+                "region {} of {} with {} plots and {} sides, starts at ({} {})",
+                region,
+                plots[0].plant,
+                plots.len(),
+                count_sides(plots),
+                plots[0].r,
+                plots[0].c
+            );
+        }
+    }
+    #[test]
+    fn check() {
+        let mut map = load_input(include_str!("test_input.txt")).unwrap();
+        analyze(&mut map);
+        dump(&map);
+        assert_eq!(140, get_price(&map));
+    }
+
+    #[test]
+    fn check2() {
+        let mut map = load_input(include_str!("test_input2.txt")).unwrap();
+        analyze(&mut map);
+        dump(&map);
+        assert_eq!(1930, get_price(&map));
+        assert_eq!(1206, get_discounted_price(&map));
+    }
+
+    #[test]
+    fn check3() {
+        let mut map = load_input(include_str!("test_input3.txt")).unwrap();
+        analyze(&mut map);
+        dump(&map);
+        assert_eq!(368, get_discounted_price(&map));
+    }
+}
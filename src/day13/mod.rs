@@ -0,0 +1,295 @@
+use regex::Regex;
+
+use crate::{
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Machine {
+    ax: i64,
+    ay: i64,
+    bx: i64,
+    by: i64,
+    x: i64,
+    y: i64,
+}
+// euclid returns gcd(a, b) with x and y such that a*x + b*y == gcd(a, b)
+fn euclid(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        return (a, 1, 0);
+    }
+    let (g, x, y) = euclid(b, a % b);
+    return (g, y, x - (a / b) * y);
+}
+
+// cheapest finds the lowest 3a + b with a*p + b*q == t over non-negative a and b
+fn cheapest(p: i128, q: i128, t: i128) -> Option<i128> {
+    match (p, q) {
+        (0, 0) => return if t == 0 { Some(0) } else { None },
+        (_, 0) => return if t % p == 0 { Some(3 * (t / p)) } else { None },
+        (0, _) => return if t % q == 0 { Some(t / q) } else { None },
+        _ => {}
+    }
+    let (g, x, y) = euclid(p, q);
+    if t % g != 0 {
+        return None;
+    }
+    // every solution is a = a0 + k*da, b = b0 - k*db, and the price moves by 3*da - db
+    // with each k, so the cheapest one is at either end of the non-negative range
+    let (a0, b0) = (x * (t / g), y * (t / g));
+    let (da, db) = (q / g, p / g);
+    let (lo, hi) = (-a0.div_euclid(da), b0.div_euclid(db));
+    if lo > hi {
+        return None;
+    }
+    let k = if 3 * da > db { lo } else { hi };
+    return Some(3 * (a0 + k * da) + b0 - k * db);
+}
+
+fn calculate(machine: &Machine) -> Option<i64> {
+    let Machine {
+        ax,
+        ay,
+        bx,
+        by,
+        x,
+        y,
+    } = *machine;
+    let [ax, ay, bx, by, x, y] = [ax, ay, bx, by, x, y].map(i128::from);
+    // doing math wasn't fun as it used to be in school :/
+    let det = ax * by - bx * ay;
+    if det == 0 {
+        // parallel buttons only reach prizes on their line, and then one axis is enough
+        if ax * y != ay * x || bx * y != by * x {
+            return None;
+        }
+        let price = if ax != 0 || bx != 0 {
+            cheapest(ax, bx, x)
+        } else if x == 0 {
+            cheapest(ay, by, y)
+        } else {
+            None
+        };
+        return price.and_then(|p| i64::try_from(p).ok());
+    }
+    let (apushes, bpushes) = (x * by - y * bx, ax * y - ay * x);
+    if apushes % det != 0 || bpushes % det != 0 {
+        return None;
+    }
+    let (apushes, bpushes) = (apushes / det, bpushes / det);
+    if apushes < 0 || bpushes < 0 {
+        return None;
+    }
+    return i64::try_from(3 * apushes + bpushes).ok();
+}
+
+// play tries every number of A pushes and lets B cover the rest of the way
+#[allow(dead_code)]
+fn play(machine: &Machine) -> Option<i64> {
+    let Machine {
+        ax,
+        ay,
+        bx,
+        by,
+        x,
+        y,
+    } = *machine;
+    let mut tokens = None;
+    for pusha in 0..=x.max(y) {
+        let (rx, ry) = (x - ax * pusha, y - ay * pusha);
+        if rx < 0 || ry < 0 {
+            break;
+        }
+        let pushb = if bx > 0 {
+            rx / bx
+        } else if by > 0 {
+            ry / by
+        } else {
+            0
+        };
+        if bx * pushb == rx && by * pushb == ry {
+            let price = pusha * 3 + pushb;
+            if tokens.is_none_or(|t| price < t) {
+                tokens = Some(price);
+            }
+        }
+    }
+    return tokens;
+}
+
+fn load_input(txt: &str) -> Result<Vec<Machine>, ParseError> {
+    let mut machines: Vec<_> = Vec::new();
+    let re = Regex::new(r"(\d+).+?(\d+)").unwrap();
+    for para in parse::sections(Day13::DAY, txt) {
+        if para.len() > 3 {
+            return Err(para[3].error(para[3].text, "an empty line"));
+        }
+        let mut nums: Vec<i64> = Vec::new();
+        for line in &para {
+            let m = re
+                .captures(line.text)
+                .ok_or_else(|| line.error(line.text, "`X<n>, Y<n>`"))?;
+            nums.push(line.number(&m[1])?);
+            nums.push(line.number(&m[2])?);
+        }
+        match nums[..] {
+            [ax, ay, bx, by, x, y] => machines.push(Machine {
+                ax,
+                ay,
+                bx,
+                by,
+                x,
+                y,
+            }),
+            [] => continue,
+            _ => {
+                return Err(para[para.len() - 1]
+                    .error_at_end("buttons A and B followed by the prize location"))
+            }
+        }
+    }
+
+    return Ok(machines);
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<Machine>;
+
+    fn parse(txt: &str) -> Result<Self::Input, ParseError> {
+        load_input(txt)
+    }
+
+    fn part1(machines: &Self::Input) -> Answer {
+        let mut spent = 0;
+        for m in machines {
+            if let Some(price) = calculate(m) {
+                spent += price;
+            }
+        }
+        spent.into()
+    }
+
+    fn part2(machines: &Self::Input) -> Answer {
+        let mut spent = 0;
+        for m in machines {
+            let m = Machine {
+                x: m.x + 10000000000000,
+                y: m.y + 10000000000000,
+                ..*m
+            };
+            if let Some(price) = calculate(&m) {
+                spent += price;
+            }
+        }
+        spent.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use super::*;
+    use crate::differential::{cross_check, shrink_num};
+    #[test]
+    fn check() {
+        let input = include_str!("test_input.txt");
+        let machines = load_input(input).unwrap();
+        let tokens: Vec<Option<i64>> = machines.iter().map(calculate).collect();
+        assert_eq!(vec![Some(280), None, Some(200), None], tokens);
+        // the exact solution presses B -104 times
+        let m = Machine {
+            ax: 73,
+            ay: 48,
+            bx: 24,
+            by: 16,
+            x: 3855,
+            y: 2512,
+        };
+        assert_eq!(None, calculate(&m));
+    }
+
+    #[test]
+    fn check_parallel() {
+        let machines = [
+            ((1, 1, 1, 1, 5, 5), Some(5)),
+            ((4, 0, 1, 0, 9, 0), Some(7)),
+            ((2, 4, 7, 14, 11, 22), Some(7)),
+            ((2, 4, 6, 12, 11, 22), None),
+            ((1, 1, 2, 2, 5, 6), None),
+            ((0, 0, 0, 0, 3, 0), None),
+        ];
+        for ((ax, ay, bx, by, x, y), expected) in machines {
+            let m = Machine {
+                ax,
+                ay,
+                bx,
+                by,
+                x,
+                y,
+            };
+            assert_eq!(expected, calculate(&m), "{m:?}");
+        }
+    }
+
+    // shrink_machine makes one field of the machine smaller
+    fn shrink_machine(m: &Machine) -> Vec<Machine> {
+        let mut smaller = Vec::new();
+        for field in 0..6 {
+            let v = [m.ax, m.ay, m.bx, m.by, m.x, m.y][field];
+            for n in shrink_num(v) {
+                let mut s = *m;
+                *[
+                    &mut s.ax, &mut s.ay, &mut s.bx, &mut s.by, &mut s.x, &mut s.y,
+                ][field] = n;
+                smaller.push(s);
+            }
+        }
+        return smaller;
+    }
+
+    #[test]
+    fn check_play_vs_calculate() {
+        let r = cross_check(
+            2000,
+            13,
+            |rng| {
+                let (ax, ay, bx, by) = if rng.gen_bool(0.25) {
+                    // parallel buttons, which leave more than one way to the prize
+                    let (dx, dy) = (rng.gen_range(1..10), rng.gen_range(1..10));
+                    let (ka, kb) = (rng.gen_range(1..10), rng.gen_range(1..10));
+                    (dx * ka, dy * ka, dx * kb, dy * kb)
+                } else {
+                    (
+                        rng.gen_range(1..100),
+                        rng.gen_range(1..100),
+                        rng.gen_range(1..100),
+                        rng.gen_range(1..100),
+                    )
+                };
+                let (a, b) = (rng.gen_range(0..100), rng.gen_range(0..100));
+                let offset = if rng.gen_bool(0.5) {
+                    0
+                } else {
+                    rng.gen_range(1..100)
+                };
+                Machine {
+                    ax,
+                    ay,
+                    bx,
+                    by,
+                    x: a * ax + b * bx + offset,
+                    y: a * ay + b * by,
+                }
+            },
+            shrink_machine,
+            play,
+            calculate,
+        );
+        r.unwrap_or_else(|m| panic!("{m}"));
+    }
+}
@@ -0,0 +1,210 @@
+use regex::Regex;
+
+use crate::{
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
+// the real area, other ones are given by a header line like `area=11x7`
+const SIZE_X: i32 = 101;
+const SIZE_Y: i32 = 103;
+// PICTURE is the smallest side of an area that can show a picture, the real tree is 31x33
+const PICTURE: i32 = 20;
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    x: i32,
+    y: i32,
+    dx: i32,
+    dy: i32,
+}
+
+fn load_input(txt: &str) -> Result<(Vec<Robot>, (i32, i32)), ParseError> {
+    let mut robots: Vec<_> = Vec::new();
+    let (mut size_x, mut size_y) = (SIZE_X, SIZE_Y);
+    let re = Regex::new(r"(\d+).+?(\d+).+?(-?\d+).+?(-?\d+)").unwrap();
+    for line in parse::lines(Day14::DAY, txt) {
+        if line.no == 1 {
+            if let Some(area) = line.text.strip_prefix("area=") {
+                let Some((x, y)) = area.split_once('x') else {
+                    return Err(line.error(area, "`<width>x<height>`"));
+                };
+                (size_x, size_y) = (line.number(x)?, line.number(y)?);
+                if size_x < 1 || size_y < 1 {
+                    return Err(line.error(area, "a non-empty area"));
+                }
+                continue;
+            }
+        }
+        let m = re
+            .captures(line.text)
+            .ok_or_else(|| line.error(line.text, "`p=<x>,<y> v=<dx>,<dy>`"))?;
+        let r = Robot {
+            x: line.number(&m[1])?,
+            y: line.number(&m[2])?,
+            dx: line.number(&m[3])?,
+            dy: line.number(&m[4])?,
+        };
+        if r.x >= size_x || r.y >= size_y {
+            return Err(line.error(&m[0], &format!("a position within {size_x}x{size_y} area")));
+        }
+        robots.push(r);
+    }
+    return Ok((robots, (size_x, size_y)));
+}
+
+fn get_map(robots: &Vec<Robot>, size_x: i32, size_y: i32) -> Vec<Vec<i32>> {
+    let mut map: Vec<Vec<i32>> = vec![vec![0; size_y as usize]; size_x as usize];
+    for r in robots {
+        map[r.x as usize][r.y as usize] += 1;
+    }
+    return map;
+}
+
+fn patrol(
+    robots: &mut Vec<Robot>,
+    steps: usize,
+    map: &mut Vec<Vec<i32>>,
+    size_x: i32,
+    size_y: i32,
+) {
+    for _step in 0..steps {
+        for robot in robots.iter_mut() {
+            map[robot.x as usize][robot.y as usize] -= 1;
+            // velocities may be larger than the area
+            robot.x = (robot.x + robot.dx).rem_euclid(size_x);
+            robot.y = (robot.y + robot.dy).rem_euclid(size_y);
+            map[robot.x as usize][robot.y as usize] += 1
+        }
+    }
+}
+
+fn get_safety_factor(map: &Vec<Vec<i32>>) -> (i32, i32, i32, i32) {
+    let mx = map.len() / 2;
+    let my = map[0].len() / 2;
+    let (mut q1, mut q2, mut q3, mut q4) = (0, 0, 0, 0);
+    for x in 0..map.len() {
+        for y in 0..map[0].len() {
+            //    mx
+            // q1 | q2
+            // ---+---- my
+            // q4 | q3
+            if x < mx && y < my {
+                q1 += map[x][y]
+            }
+            if x > mx && y < my {
+                q2 += map[x][y]
+            }
+            if x > mx && y > my {
+                q3 += map[x][y]
+            }
+            if x < mx && y > my {
+                q4 += map[x][y]
+            }
+        }
+    }
+    return (q1, q2, q3, q4);
+}
+
+// spread tells how far apart the robots are after t seconds: the variances of both coordinates
+// scaled by the number of robots squared, so it stays in integers
+fn spread(robots: &Vec<Robot>, t: i64, size_x: i32, size_y: i32) -> i64 {
+    let n = robots.len() as i64;
+    let (mut sx, mut sxx, mut sy, mut syy) = (0i64, 0i64, 0i64, 0i64);
+    for r in robots {
+        let x = (r.x as i64 + r.dx as i64 * t).rem_euclid(size_x as i64);
+        let y = (r.y as i64 + r.dy as i64 * t).rem_euclid(size_y as i64);
+        sx += x;
+        sxx += x * x;
+        sy += y;
+        syy += y * y;
+    }
+    return n * sxx - sx * sx + n * syy - sy * sy;
+}
+
+// picture finds the second the robots huddle together the most over a whole period of the area,
+// after width x height seconds they are back where they started
+fn picture(robots: &Vec<Robot>, size_x: i32, size_y: i32) -> Option<i64> {
+    if size_x < PICTURE || size_y < PICTURE || robots.is_empty() {
+        return None;
+    }
+    return (0..size_x as i64 * size_y as i64).min_by_key(|t| spread(robots, *t, size_x, size_y));
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    // robots along with the (width, height) of the area they patrol
+    type Input = (Vec<Robot>, (i32, i32));
+
+    fn parse(txt: &str) -> Result<Self::Input, ParseError> {
+        load_input(txt)
+    }
+
+    fn part1((robots, (size_x, size_y)): &Self::Input) -> Answer {
+        let mut robots = robots.clone();
+        let mut map = get_map(&robots, *size_x, *size_y);
+        patrol(&mut robots, 100, &mut map, *size_x, *size_y);
+        let (q1, q2, q3, q4) = get_safety_factor(&map);
+        (q1 * q2 * q3 * q4).into()
+    }
+
+    fn part2((robots, (size_x, size_y)): &Self::Input) -> Answer {
+        match picture(robots, *size_x, *size_y) {
+            Some(t) => t.into(),
+            None => Answer::Unsolved,
+        }
+    }
+
+    fn diagnostics(
+        (robots, (size_x, size_y)): &Self::Input,
+        part: u8,
+    ) -> Vec<(&'static str, String)> {
+        let mut d = vec![("area", format!("{size_x}x{size_y}"))];
+        if part == 2 && picture(robots, *size_x, *size_y).is_none() {
+            let why = if robots.is_empty() {
+                "there are no robots to show one".to_string()
+            } else {
+                format!("an area under {PICTURE}x{PICTURE} is too small to show one")
+            };
+            d.push(("picture", why));
+        }
+        return d;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check() {
+        let (mut robots, (size_x, size_y)) = Day14::parse(include_str!("test_input.txt")).unwrap();
+        assert_eq!((11, 7), (size_x, size_y));
+        let mut map = get_map(&robots, size_x, size_y);
+        patrol(&mut robots, 100, &mut map, size_x, size_y);
+        let (q1, q2, q3, q4) = get_safety_factor(&map);
+        assert_eq!(12, q1 * q2 * q3 * q4);
+        let input = Day14::parse(include_str!("test_input.txt")).unwrap();
+        assert_eq!(Answer::Unsolved, Day14::part2(&input));
+        assert_eq!(
+            (
+                "picture",
+                "an area under 20x20 is too small to show one".to_string()
+            ),
+            Day14::diagnostics(&input, 2)[1]
+        );
+        let (_, area) = Day14::parse("p=0,4 v=3,-3").unwrap();
+        assert_eq!((SIZE_X, SIZE_Y), area);
+        let (robots, area) = Day14::parse("area=2000x3\np=1999,2 v=-4001,7").unwrap();
+        assert_eq!((2000, 3), area);
+        let mut map = get_map(&robots, 2000, 3);
+        let mut robots = robots.clone();
+        patrol(&mut robots, 1, &mut map, 2000, 3);
+        assert_eq!((1998, 0), (robots[0].x, robots[0].y));
+        for txt in ["area=0x7\n", "area=11\n", "area=11x7\np=11,0 v=1,1"] {
+            assert!(Day14::parse(txt).is_err(), "{txt:?}");
+        }
+    }
+}
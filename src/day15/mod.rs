@@ -0,0 +1,318 @@
+use std::{collections::HashSet, hash::Hash, thread::sleep, time};
+
+use console::Term;
+
+use crate::{
+    grid::{Dir, Grid, Pos},
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Cell {
+    Empty,
+    Wall,
+    Robot,
+    Box,
+    LCrate,
+    RCrate,
+}
+
+impl From<char> for Cell {
+    fn from(val: char) -> Self {
+        match val {
+            '#' => Cell::Wall,
+            'O' => Cell::Box,
+            '@' => Cell::Robot,
+            '[' => Cell::LCrate,
+            ']' => Cell::RCrate,
+            _ => Cell::Empty,
+        }
+    }
+}
+
+impl From<Cell> for char {
+    fn from(t: Cell) -> char {
+        match t {
+            Cell::Wall => '#',
+            Cell::Box => 'O',
+            Cell::Robot => '@',
+            Cell::LCrate => '[',
+            Cell::RCrate => ']',
+            _ => '.',
+        }
+    }
+}
+
+type Map = Grid<Cell>;
+
+fn load_input(txt: &str) -> Result<(Map, Vec<Dir>, Pos), ParseError> {
+    let sections = parse::sections(Day15::DAY, txt);
+    let map_txt = sections[0]
+        .iter()
+        .map(|line| line.text)
+        .collect::<Vec<&str>>()
+        .join("\n");
+    let map = Grid::parse(Day15::DAY, &map_txt, "a map cell", |c| {
+        "#.O@[]".contains(c).then(|| Cell::from(c))
+    })?;
+    let robot_pos = map
+        .find(|c| *c == Cell::Robot)
+        .ok_or_else(|| parse::missing(Day15::DAY, txt, "a robot `@`"))?;
+    // the walls around keep every push inside the map, and there is just one robot to push
+    let (last_r, last_c) = (map.height() as i32 - 1, map.width() as i32 - 1);
+    for ((r, c), cell) in map.iter() {
+        let line = &sections[0][r as usize];
+        let at = &line.text[c as usize..c as usize + 1];
+        let border = r == 0 || c == 0 || r == last_r || c == last_c;
+        if border && *cell != Cell::Wall {
+            return Err(line.error(at, "a wall `#` around the map"));
+        }
+        if *cell == Cell::Robot && (r, c) != robot_pos {
+            return Err(line.error(at, "a single robot `@`"));
+        }
+    }
+    if sections.len() < 2 {
+        return Err(parse::missing(Day15::DAY, txt, "moves"));
+    }
+    let mut moves = Vec::new();
+    for line in sections[1..].iter().flatten() {
+        for (col, c) in line.text.char_indices() {
+            match Dir::from_arrow(c) {
+                Some(d) => moves.push(d),
+                None => return Err(line.error(&line.text[col..], "a move")),
+            }
+        }
+    }
+
+    return Ok((map, moves, robot_pos));
+}
+
+// print draws the map with the robot shown as an arrow of its next move
+fn print(term: &Term, map: &Map, m: Option<Dir>) {
+    term.move_cursor_to(0, 0).unwrap();
+    let robot = m.map_or('@', |d| d.arrow());
+    for row in map.rows() {
+        term.write_line(
+            &row.iter()
+                .map(|x| match x {
+                    Cell::Robot => robot,
+                    x => char::from(x.clone()),
+                })
+                .collect::<String>(),
+        )
+        .unwrap();
+    }
+}
+
+fn collect_movables(map: &Map, cell: &Pos, m: &Dir) -> Option<Vec<Pos>> {
+    let next = m.step(*cell);
+    match map[*cell] {
+        Cell::Empty => Some(vec![]),
+        // the robot never pushes towards itself, so it is only there to be safe
+        Cell::Wall | Cell::Robot => None,
+        Cell::Box => match collect_movables(map, &next, m) {
+            None => return None,
+            Some(mut c) => {
+                c.push(*cell);
+                Some(c)
+            }
+        },
+        Cell::LCrate if *m == Dir::Left || *m == Dir::Right => {
+            match collect_movables(map, &next, m) {
+                None => return None,
+                Some(mut c) => {
+                    c.push(*cell);
+                    Some(c)
+                }
+            }
+        }
+        Cell::RCrate if *m == Dir::Left || *m == Dir::Right => {
+            match collect_movables(map, &next, m) {
+                None => return None,
+                Some(mut c) => {
+                    c.push(*cell);
+                    Some(c)
+                }
+            }
+        }
+        Cell::LCrate => {
+            match (
+                collect_movables(map, &next, m),
+                collect_movables(map, &(next.0, next.1 + 1), m),
+            ) {
+                (None, _) => return None,
+                (_, None) => return None,
+                (Some(mut a), Some(mut b)) => {
+                    a.append(&mut b);
+                    a.push(*cell);
+                    a.push((cell.0, cell.1 + 1));
+                    return Some(a);
+                }
+            }
+        }
+        Cell::RCrate => {
+            match (
+                collect_movables(map, &next, m),
+                collect_movables(map, &(next.0, next.1 - 1), m),
+            ) {
+                (None, _) => return None,
+                (_, None) => return None,
+                (Some(mut a), Some(mut b)) => {
+                    a.append(&mut b);
+                    a.push(*cell);
+                    a.push((cell.0, cell.1 - 1));
+                    return Some(a);
+                }
+            }
+        }
+    }
+}
+
+fn move_robot(map: &mut Map, moves: &Vec<Dir>, robot_pos: &mut Pos, interactive: bool) {
+    let term = Term::stdout();
+    if interactive {
+        term.clear_screen().unwrap();
+    }
+    for (i, m) in moves.iter().enumerate() {
+        if interactive {
+            // term.clear_screen();
+            print(&term, map, Some(*m));
+            term.write_line(&format!(
+                "step {i} robot at {:?}, moving {}    ",
+                robot_pos,
+                match *m {
+                    Dir::Up => "up",
+                    Dir::Down => "down",
+                    Dir::Left => "left",
+                    Dir::Right => "right",
+                }
+            ))
+            .unwrap();
+        }
+        let next = m.step(*robot_pos);
+        match collect_movables(map, &next, m) {
+            None => continue,
+            Some(cells) => {
+                let old_map = map.clone();
+                let mut hs = HashSet::new();
+                for cell in cells {
+                    if hs.contains(&cell) {
+                        continue;
+                    }
+                    hs.insert(cell);
+                    map[m.step(cell)] = old_map[cell].clone();
+                    map[cell] = Cell::Empty;
+                }
+                map[*robot_pos] = Cell::Empty;
+                map[next] = Cell::Robot;
+                *robot_pos = next;
+            }
+        }
+        if interactive {
+            // stdin().read(&mut [0]).unwrap();
+            sleep(time::Duration::from_millis(100));
+        }
+    }
+}
+
+fn calc(map: &Map) -> i32 {
+    let mut sum = 0;
+    for ((r, c), cell) in map.iter() {
+        match cell {
+            Cell::Box => sum += 100 * r + c,
+            Cell::LCrate => sum += 100 * r + c,
+            _ => {}
+        }
+    }
+    return sum;
+}
+
+fn get_wide_map(map: &Map, robot_pos: Pos) -> (Map, Pos) {
+    let mut cells = Vec::new();
+    for (_, cell) in map.iter() {
+        match cell {
+            Cell::Box => cells.append(&mut vec![Cell::LCrate, Cell::RCrate]),
+            Cell::Robot => cells.append(&mut vec![Cell::Robot, Cell::Empty]),
+            x => cells.append(&mut vec![x.clone(), x.clone()]),
+        }
+    }
+    let wide_map = Grid::from_vec(map.width() * 2, map.height(), cells);
+    return (wide_map, (robot_pos.0, robot_pos.1 * 2));
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = (Map, Vec<Dir>, Pos);
+
+    fn parse(txt: &str) -> Result<Self::Input, ParseError> {
+        load_input(txt)
+    }
+
+    fn part1((map, moves, robot_pos): &Self::Input) -> Answer {
+        let (mut map, mut robot_pos) = (map.clone(), *robot_pos);
+        move_robot(&mut map, moves, &mut robot_pos, false);
+        calc(&map).into()
+    }
+
+    fn part2((map, moves, robot_pos): &Self::Input) -> Answer {
+        let (mut wide_map, mut robot_pos) = get_wide_map(map, *robot_pos);
+        move_robot(&mut wide_map, moves, &mut robot_pos, false);
+        calc(&wide_map).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn check1() {
+        let input = include_str!("test_input.txt");
+        let term = Term::stdout();
+        let (mut map, moves, mut robot_pos) = load_input(input).unwrap();
+        move_robot(&mut map, &moves, &mut robot_pos, false);
+        print(&term, &map, None);
+        assert_eq!(10092, calc(&map))
+    }
+
+    #[test]
+    fn check2() {
+        let input = include_str!("test_input.txt");
+        let (map, moves, robot_pos) = load_input(input).unwrap();
+        let (mut map, mut robot_pos) = get_wide_map(&map, robot_pos);
+        move_robot(&mut map, &moves, &mut robot_pos, false);
+        assert_eq!(9021, calc(&map))
+    }
+
+    #[test]
+    fn check3() {
+        let input = include_str!("test_input2.txt");
+        let (mut map, moves, mut robot_pos) = load_input(input).unwrap();
+        move_robot(&mut map, &moves, &mut robot_pos, true);
+        assert_eq!((4, 4), robot_pos);
+        assert_eq!(812, calc(&map));
+    }
+
+    #[test]
+    fn check_malformed() {
+        let errors = [
+            (
+                "#####\n#@.O.\n#####\n\n<",
+                (2, 5),
+                "a wall `#` around the map",
+            ),
+            ("#####\n#@.@#\n#####\n\n<", (2, 4), "a single robot `@`"),
+            ("#####\n#...#\n#####\n\n<", (6, 1), "a robot `@`"),
+        ];
+        for (txt, at, expected) in errors {
+            let e = load_input(txt).unwrap_err();
+            assert_eq!(
+                (at, expected),
+                ((e.line, e.column), e.expected.as_str()),
+                "{txt:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use pathfinding::prelude::astar_bag;
+use pathfinding::prelude::AstarSolution;
+use std::{collections::HashSet, hash::Hash};
+
+use crate::{
+    grid::{Dir, Grid, Pos},
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Cell {
+    Empty,
+    Wall,
+    Start,
+    End,
+}
+
+impl From<char> for Cell {
+    fn from(val: char) -> Self {
+        match val {
+            '#' => Cell::Wall,
+            'S' => Cell::Start,
+            'E' => Cell::End,
+            _ => Cell::Empty,
+        }
+    }
+}
+
+fn load_input(txt: &str) -> Result<(Grid<Cell>, Pos), ParseError> {
+    let map = Grid::parse(Day16::DAY, txt, "a maze cell", |c| {
+        "#.SE".contains(c).then(|| Cell::from(c))
+    })?;
+    let start_pos = map
+        .find(|c| *c == Cell::Start)
+        .ok_or_else(|| parse::missing(Day16::DAY, txt, "a start `S`"))?;
+    if map.find(|c| *c == Cell::End).is_none() {
+        return Err(parse::missing(Day16::DAY, txt, "an end `E`"));
+    }
+    return Ok((map, start_pos));
+}
+
+fn get_best(map: &Grid<Cell>, p: Pos) -> Option<(AstarSolution<(Pos, Dir)>, i32)> {
+    let end_pos = map.find(|c| *c == Cell::End).unwrap();
+    astar_bag(
+        &(p, Dir::Right),
+        |(p, dir)| {
+            let mut s = Vec::new();
+            for (d, cost) in get_moves(*dir) {
+                let n = d.step(*p);
+                if map.get(n).is_some_and(|c| *c != Cell::Wall) {
+                    s.push(((n, d), cost))
+                }
+            }
+            return s;
+        },
+        |(p, _)| (end_pos.0 - p.0).abs() + (end_pos.1 - p.1).abs(),
+        |(p, _)| map[*p] == Cell::End,
+    )
+}
+
+fn get_moves(d: Dir) -> [(Dir, i32); 3] {
+    [(d, 1), (d.turn_left(), 1001), (d.turn_right(), 1001)]
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = (Grid<Cell>, Pos);
+
+    fn parse(txt: &str) -> Result<Self::Input, ParseError> {
+        load_input(txt)
+    }
+
+    fn part1((map, start_pos): &Self::Input) -> Answer {
+        match get_best(map, *start_pos) {
+            Some((_, score)) => score.into(),
+            None => Answer::Unsolved,
+        }
+    }
+
+    fn part2((map, start_pos): &Self::Input) -> Answer {
+        let Some((paths, _)) = get_best(map, *start_pos) else {
+            return Answer::Unsolved;
+        };
+        let mut tiles = HashSet::new();
+        for path in paths {
+            for point in path {
+                tiles.insert(point.0);
+            }
+        }
+        tiles.len().into()
+    }
+
+    fn diagnostics((map, start_pos): &Self::Input, _part: u8) -> Vec<(&'static str, String)> {
+        match get_best(map, *start_pos) {
+            Some(_) => Vec::new(),
+            None => vec![("route", "walls cut the end off from the start".to_string())],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_no_route() {
+        let input = Day16::parse("#####\n#S#E#\n#####").unwrap();
+        assert_eq!(Answer::Unsolved, Day16::part1(&input));
+        assert_eq!(Answer::Unsolved, Day16::part2(&input));
+        assert_eq!(
+            vec![("route", "walls cut the end off from the start".to_string())],
+            Day16::diagnostics(&input, 1)
+        );
+    }
+}
@@ -0,0 +1,209 @@
+use crate::{
+    parse::{self, Line, ParseError},
+    Answer, Solution,
+};
+
+#[allow(dead_code)]
+const OPCODES: [&'static str; 8] = ["adv", "bxl", "bst", "jnz", "bxc", "out", "bdv", "cdv"];
+#[allow(dead_code)]
+const OPERANDS: [&'static str; 8] = ["0", "1", "2", "3", "A", "B", "C", "7"];
+
+#[derive(Debug, Clone)]
+pub struct Computer {
+    a: u64,
+    b: u64,
+    c: u64,
+    ip: usize,
+    prog: Vec<u64>,
+    out: Vec<u64>,
+}
+fn get_val<'a>(line: &Line<'a>, name: &str) -> Result<&'a str, ParseError> {
+    line.text
+        .strip_prefix(name)
+        .and_then(|v| v.strip_prefix(": "))
+        .ok_or_else(|| line.error(line.text, &format!("`{name}: <value>`")))
+}
+impl Computer {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let lines: Vec<Line> = parse::lines(Day17::DAY, input).collect();
+        if lines.len() < 5 {
+            return Err(parse::missing(Day17::DAY, input, "registers and program"));
+        }
+        if !lines[3].text.is_empty() {
+            return Err(lines[3].error(lines[3].text, "an empty line"));
+        }
+        Ok(Self {
+            a: lines[0].number(get_val(&lines[0], "Register A")?)?,
+            b: lines[1].number(get_val(&lines[1], "Register B")?)?,
+            c: lines[2].number(get_val(&lines[2], "Register C")?)?,
+            ip: 0,
+            prog: get_val(&lines[4], "Program")?
+                .split(',')
+                .map(|x| lines[4].number(x))
+                .collect::<Result<_, _>>()?,
+            out: Vec::new(),
+        })
+    }
+    fn combo_operand(&self, v: u64) -> Result<u64, &'static str> {
+        match v {
+            0..=3 => Ok(v),
+            4 => Ok(self.a),
+            5 => Ok(self.b),
+            6 => Ok(self.c),
+            _ => Err("invalid combo operand 7"),
+        }
+    }
+
+    // divide is A divided by 2 to the power of the combo operand, shifting out every bit
+    // when the power is 64 or more
+    fn divide(&self, operand: u64) -> Result<u64, &'static str> {
+        let power = self.combo_operand(operand)?;
+        return Ok(u32::try_from(power)
+            .ok()
+            .and_then(|p| self.a.checked_shr(p))
+            .unwrap_or(0));
+    }
+
+    fn step(&mut self, opcode: u64, operand: u64) -> Result<(), &'static str> {
+        let mut inc_ip = true;
+        match opcode {
+            /* adv */ 0 => self.a = self.divide(operand)?,
+            /* bxl */ 1 => self.b = self.b ^ operand,
+            /* bst */ 2 => self.b = self.combo_operand(operand)? % 8,
+            /* jnz */
+            3 => {
+                if self.a != 0 {
+                    inc_ip = false;
+                    self.ip = operand as usize;
+                }
+            }
+            /* bxc */ 4 => self.b = self.b ^ self.c,
+            /* out */ 5 => self.out.push(self.combo_operand(operand)? % 8),
+            /* bdv */ 6 => self.b = self.divide(operand)?,
+            /* cdv */ 7 => self.c = self.divide(operand)?,
+            /* nop */ _ => return Err("invalid opcode"),
+        };
+        if inc_ip {
+            self.ip += 2;
+        }
+        Ok(())
+    }
+
+    // run executes the program until it halts, it fails on invalid instructions
+    // or when it is still running after limit steps
+    fn run(&mut self, limit: usize) -> Result<Vec<u64>, String> {
+        for _step in 0..limit {
+            if self.ip + 1 >= self.prog.len() {
+                return Ok(self.out.clone());
+            }
+            let opcode = self.prog[self.ip];
+            let operand = self.prog[self.ip + 1];
+            self.step(opcode, operand)
+                .map_err(|e| format!("{e} at position {}", self.ip))?;
+        }
+        return Err(format!("does not halt within {limit} steps"));
+    }
+}
+
+// find_a builds A three bits at a time from the last output digit backwards: the programs
+// print one digit of A per loop and shift it right by 3, so the digits already found
+// decide the end of the output. It gives None when no A makes the program print itself.
+fn find_a(orig: &Computer) -> Option<u64> {
+    fn search(orig: &Computer, a: u64, left: usize) -> Option<u64> {
+        if left == 0 {
+            return Some(a);
+        }
+        for digit in 0..8 {
+            let next = (a << 3) | digit;
+            if next == 0 {
+                continue;
+            }
+            let mut comp = orig.clone();
+            comp.a = next;
+            if comp.run(10_000).ok().as_deref() == Some(&orig.prog[left - 1..]) {
+                if let Some(found) = search(orig, next, left - 1) {
+                    return Some(found);
+                }
+            }
+        }
+        return None;
+    }
+    return search(orig, 0, orig.prog.len());
+}
+
+// STEPS bounds the run of part 1, the programs of the puzzle halt after a few hundred
+const STEPS: usize = 1000;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input = Computer;
+
+    fn parse(txt: &str) -> Result<Self::Input, ParseError> {
+        Computer::new(txt)
+    }
+
+    fn part1(comp: &Self::Input) -> Answer {
+        let Ok(out) = comp.clone().run(STEPS) else {
+            return Answer::Unsolved;
+        };
+        out.iter()
+            .map(u64::to_string)
+            .collect::<Vec<String>>()
+            .join(",")
+            .into()
+    }
+
+    fn part2(comp: &Self::Input) -> Answer {
+        match find_a(comp) {
+            Some(a) => a.into(),
+            None => Answer::Unsolved,
+        }
+    }
+
+    fn diagnostics(comp: &Self::Input, part: u8) -> Vec<(&'static str, String)> {
+        if part == 1 {
+            if let Err(e) = comp.clone().run(STEPS) {
+                return vec![("program", e)];
+            }
+        } else if find_a(comp).is_none() {
+            return vec![("program", "no value of A makes it print itself".to_string())];
+        }
+        return Vec::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_unsolved() {
+        let header = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: ";
+        let spins = Day17::parse(&format!("{header}3,0")).unwrap();
+        assert_eq!(Answer::Unsolved, Day17::part1(&spins));
+        assert_eq!(
+            vec![("program", "does not halt within 1000 steps".to_string())],
+            Day17::diagnostics(&spins, 1)
+        );
+        let invalid = Day17::parse(&format!("{header}0,1,5,7")).unwrap();
+        assert_eq!(Answer::Unsolved, Day17::part1(&invalid));
+        assert_eq!(
+            vec![(
+                "program",
+                "invalid combo operand 7 at position 2".to_string()
+            )],
+            Day17::diagnostics(&invalid, 1)
+        );
+        let shifted = "Register A: 5\nRegister B: 64\nRegister C: 0\n\nProgram: 0,5,5,4";
+        let shifted = Day17::parse(shifted).unwrap();
+        assert_eq!(Answer::Text("0".to_string()), Day17::part1(&shifted));
+        let example = Day17::parse(include_str!("test_input2.txt")).unwrap();
+        assert_eq!(Answer::Unsolved, Day17::part2(&example));
+        assert_eq!(
+            vec![("program", "no value of A makes it print itself".to_string())],
+            Day17::diagnostics(&example, 2)
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use pathfinding::prelude::astar;
+
+use crate::{
+    grid::{Grid, Pos},
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
+pub type Point = (i32, i32);
+
+// Space is the side of the memory space and how many bytes have fallen for part 1,
+// the real one unless the input starts with a header line like `size=7 fallen=12`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Space {
+    pub size: i32,
+    pub fallen: usize,
+}
+
+const REAL: Space = Space {
+    size: 71,
+    fallen: 1024,
+};
+
+fn load_input(txt: &str) -> Result<(Space, Vec<Point>), ParseError> {
+    let mut space = REAL;
+    let mut bytes = Vec::new();
+    for l in parse::lines(Day18::DAY, txt) {
+        if l.no == 1 && l.text.starts_with("size=") {
+            let header = match l.text.split_whitespace().collect::<Vec<&str>>()[..] {
+                [size, fallen] => size
+                    .strip_prefix("size=")
+                    .zip(fallen.strip_prefix("fallen=")),
+                _ => None,
+            };
+            let Some((size, fallen)) = header else {
+                return Err(l.error(l.text, "`size=<side> fallen=<bytes>`"));
+            };
+            space = Space {
+                size: l.number(size)?,
+                fallen: l.number(fallen)?,
+            };
+            if space.size < 1 {
+                return Err(l.error(size, "a non-empty memory space"));
+            }
+            continue;
+        }
+        let byte: Point = match l.text.split(',').collect::<Vec<&str>>()[..] {
+            [x, y] => (l.number(x)?, l.number(y)?),
+            _ => return Err(l.error(l.text, "`X,Y`")),
+        };
+        if byte.0 >= space.size || byte.1 >= space.size {
+            let size = space.size;
+            return Err(l.error(l.text, &format!("a byte within {size}x{size} memory space")));
+        }
+        bytes.push(byte);
+    }
+    return Ok((space, bytes));
+}
+
+fn get_memory(bytes: &[Point], size: i32) -> Grid<bool> {
+    let mut memory = Grid::new(size as usize, size as usize, false);
+    for (x, y) in bytes {
+        if let Some(corrupted) = memory.get_mut((*y, *x)) {
+            *corrupted = true;
+        }
+    }
+    return memory;
+}
+
+fn find_path(bytes: &[Point], size: i32) -> Option<(Vec<Pos>, i32)> {
+    let memory = get_memory(bytes, size);
+    let exit = size - 1;
+    astar(
+        &(0, 0),
+        |pos| {
+            memory
+                .neighbours4(*pos)
+                .filter(|(_, p)| !memory[*p])
+                .map(|(_, p)| (p, 1))
+                .collect::<Vec<(Pos, i32)>>()
+        },
+        |pos| exit - pos.0 + exit - pos.1,
+        |pos| *pos == (exit, exit),
+    )
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Input = (Space, Vec<Point>);
+
+    fn parse(txt: &str) -> Result<Self::Input, ParseError> {
+        load_input(txt)
+    }
+
+    fn part1((space, bytes): &Self::Input) -> Answer {
+        match find_path(&bytes[..space.fallen.min(bytes.len())], space.size) {
+            Some((_steps, score)) => score.into(),
+            None => Answer::Unsolved,
+        }
+    }
+
+    fn part2((space, bytes): &Self::Input) -> Answer {
+        for cutoff in 0..bytes.len() {
+            if find_path(&bytes[..=cutoff], space.size).is_none() {
+                return Answer::Coord(bytes[cutoff].0 as i64, bytes[cutoff].1 as i64);
+            }
+        }
+        Answer::Unsolved
+    }
+
+    fn diagnostics((space, bytes): &Self::Input, part: u8) -> Vec<(&'static str, String)> {
+        let size = space.size;
+        let mut d = vec![("memory size", format!("{size}x{size}"))];
+        if part == 1 {
+            d.push(("fallen bytes", space.fallen.min(bytes.len()).to_string()));
+        }
+        return d;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check() {
+        let input = load_input(include_str!("test_input.txt")).unwrap();
+        assert_eq!(
+            Space {
+                size: 7,
+                fallen: 12
+            },
+            input.0
+        );
+        assert_eq!(Answer::Num(22), Day18::part1(&input));
+        assert_eq!(Answer::Coord(6, 1), Day18::part2(&input));
+        assert_eq!(REAL, load_input("5,4\n").unwrap().0);
+        for txt in ["size=7\n", "size=0 fallen=1\n", "size=7 fallen=12\n7,0\n"] {
+            assert!(load_input(txt).is_err(), "{txt:?}");
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use rayon::prelude::*;
+
+use crate::{
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
+fn load_input(txt: &str) -> Result<(Vec<String>, Vec<String>), ParseError> {
+    match &parse::sections(Day19::DAY, txt)[..] {
+        [patterns, designs] if patterns.len() == 1 => Ok((
+            patterns[0].text.split(", ").map(str::to_string).collect(),
+            designs.iter().map(|l| l.text.to_string()).collect(),
+        )),
+        [patterns, ..] if patterns.len() > 1 => {
+            Err(patterns[1].error(patterns[1].text, "an empty line"))
+        }
+        [_, _, extra, ..] => Err(extra[0].error(extra[0].text, "a design")),
+        _ => Err(parse::missing(
+            Day19::DAY,
+            txt,
+            "towel patterns and designs separated by an empty line",
+        )),
+    }
+}
+
+fn is_possible(patterns: &Vec<String>, design: &str) -> bool {
+    if design.is_empty() {
+        return true;
+    }
+    for pattern in patterns {
+        if design.starts_with(pattern) && is_possible(patterns, &design[pattern.len()..]) {
+            return true;
+        }
+    }
+    return false;
+}
+
+fn count_possible(
+    patterns: &Vec<String>,
+    design: &str,
+    possibles: &mut Arc<RwLock<HashMap<String, usize>>>,
+) -> usize {
+    if design.is_empty() {
+        return 1;
+    }
+    if let Some(count) = possibles.read().unwrap().get(design) {
+        return *count;
+    }
+    let mut count = 0;
+    for pattern in patterns {
+        if design.starts_with(pattern) {
+            count += count_possible(patterns, &design[pattern.len()..], possibles);
+        }
+    }
+    possibles.write().unwrap().insert(design.to_string(), count);
+    return count;
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Input = (Vec<String>, Vec<String>);
+
+    fn parse(txt: &str) -> Result<Self::Input, ParseError> {
+        load_input(txt)
+    }
+
+    fn part1((patterns, designs): &Self::Input) -> Answer {
+        designs
+            .par_iter()
+            .filter(|x| is_possible(patterns, x))
+            .count()
+            .into()
+    }
+
+    fn part2((patterns, designs): &Self::Input) -> Answer {
+        let cache: Arc<RwLock<HashMap<String, usize>>> = Arc::new(RwLock::new(HashMap::new()));
+        designs
+            .par_iter()
+            .filter(|x| is_possible(patterns, x))
+            .map(|x| {
+                let mut cache = Arc::clone(&cache);
+                count_possible(patterns, x, &mut cache)
+            })
+            .sum::<usize>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use super::*;
+    use crate::differential::{cross_check, shrink_vec};
+    #[test]
+    fn check1() {
+        let input = include_str!("test_input.txt");
+        let (patterns, designs) = load_input(input).unwrap();
+        let possible = designs.iter().filter(|x| is_possible(&patterns, x)).count();
+        assert_eq!(6, possible);
+    }
+
+    #[test]
+    fn check2() {
+        let input = include_str!("test_input.txt");
+        let (patterns, designs) = load_input(input).unwrap();
+        let cache: Arc<RwLock<HashMap<String, usize>>> = Arc::new(RwLock::new(HashMap::new()));
+        let counts: Vec<usize> = designs
+            .into_par_iter()
+            .map(|x| {
+                let mut cache = Arc::clone(&cache);
+                count_possible(&patterns, &x, &mut cache)
+            })
+            .collect();
+        assert_eq!(vec![2, 1, 4, 6, 0, 1, 2, 0], counts);
+    }
+
+    fn stripes(rng: &mut rand::rngs::StdRng, len: usize) -> String {
+        (0..len)
+            .map(|_| ['w', 'u', 'b'][rng.gen_range(0..3)])
+            .collect()
+    }
+
+    #[test]
+    fn check_possible_vs_count() {
+        let r = cross_check(
+            500,
+            19,
+            |rng| {
+                let patterns: Vec<String> = (0..rng.gen_range(1..6))
+                    .map(|_| {
+                        let len = rng.gen_range(1..4);
+                        stripes(rng, len)
+                    })
+                    .collect();
+                let len = rng.gen_range(0..12);
+                (patterns, stripes(rng, len))
+            },
+            |(patterns, design)| {
+                let mut smaller: Vec<_> = shrink_vec(patterns)
+                    .into_iter()
+                    .map(|p| (p, design.clone()))
+                    .collect();
+                for i in 0..design.len() {
+                    let mut d = design.clone();
+                    d.remove(i);
+                    smaller.push((patterns.clone(), d));
+                }
+                smaller
+            },
+            |(patterns, design)| is_possible(patterns, design),
+            |(patterns, design)| {
+                let mut cache = Arc::new(RwLock::new(HashMap::new()));
+                count_possible(patterns, design, &mut cache) > 0
+            },
+        );
+        r.unwrap_or_else(|m| panic!("{m}"));
+    }
+}
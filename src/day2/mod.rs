@@ -0,0 +1,361 @@
+use std::{fmt, str::FromStr};
+
+use crate::{
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
+// Problem is the first thing that makes a report unsafe, at is the index of the offending level
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Problem {
+    // the step to this level is too small or too large
+    Gap { at: usize, step: i64 },
+    // the levels turn around at this level
+    Flip { at: usize },
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Problem::Gap { at, step } => write!(f, "step of {step} to level {}", at + 1),
+            Problem::Flip { at } => write!(f, "direction flip at level {}", at + 1),
+        }
+    }
+}
+
+// Diagnosis tells why a report is unsafe and which levels, if any, can be removed to fix it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnosis {
+    pub problem: Option<Problem>,
+    // indices of the removed levels, None when more removals than allowed would be needed
+    pub removed: Option<Vec<usize>>,
+}
+
+impl Diagnosis {
+    pub fn safe(&self) -> bool {
+        self.removed.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Monotonic {
+    // levels only increase or only decrease, equal neighbours break the report
+    Strict,
+    // levels never turn around; equal neighbours are only fine when min_step is 0 or
+    // plateaus are allowed, otherwise this is the same as strict
+    NonStrict,
+}
+
+impl FromStr for Monotonic {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "strict" => Ok(Monotonic::Strict),
+            "non-strict" => Ok(Monotonic::NonStrict),
+            _ => Err(format!(
+                "unknown monotonic mode {s:?}, expected strict or non-strict"
+            )),
+        }
+    }
+}
+
+// Rules tell which reports are safe
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    // bounds of the change between neighbouring levels
+    pub min_step: i32,
+    pub max_step: i32,
+    pub monotonic: Monotonic,
+    // equal neighbours (a plateau) are fine even if min_step is above 0, needs non-strict
+    pub allow_plateau: bool,
+}
+
+impl Rules {
+    // PUZZLE are the rules of the nuclear plant reports
+    pub const PUZZLE: Rules = Rules {
+        min_step: 1,
+        max_step: 3,
+        monotonic: Monotonic::Strict,
+        allow_plateau: false,
+    };
+
+    // new makes sure the rules can be met at all
+    pub fn new(
+        min_step: i32,
+        max_step: i32,
+        monotonic: Monotonic,
+        allow_plateau: bool,
+    ) -> Result<Rules, String> {
+        if min_step < 0 || max_step < min_step {
+            return Err(format!(
+                "steps from {min_step} to {max_step} are not a range of sizes"
+            ));
+        }
+        if allow_plateau && monotonic == Monotonic::Strict {
+            return Err("plateaus are only allowed with non-strict monotonic levels".to_string());
+        }
+        return Ok(Rules {
+            min_step,
+            max_step,
+            monotonic,
+            allow_plateau,
+        });
+    }
+
+    // flat tells whether neighbours may be equal
+    fn flat(&self) -> bool {
+        return self.monotonic == Monotonic::NonStrict
+            && (self.allow_plateau || self.min_step == 0);
+    }
+
+    // fits tells whether a step from a to b is allowed when going in the direction of sign
+    fn fits(&self, a: i32, b: i32, sign: i32) -> bool {
+        // levels span the whole i32 range, so the step between them needs to be wider
+        let step = i64::from(b) - i64::from(a);
+        if step == 0 {
+            return self.flat();
+        }
+        return step.signum() == i64::from(sign) && self.sized(step);
+    }
+
+    // sized tells whether a non-zero step is within min_step and max_step
+    fn sized(&self, step: i64) -> bool {
+        return i64::from(self.min_step) <= step.abs() && step.abs() <= i64::from(self.max_step);
+    }
+
+    // problem finds the first unsafe step of the report
+    fn problem(&self, r: &Vec<i32>) -> Option<Problem> {
+        let mut sign = 0;
+        for i in 1..r.len() {
+            let step = i64::from(r[i]) - i64::from(r[i - 1]);
+            if step == 0 {
+                if !self.flat() {
+                    return Some(Problem::Gap { at: i, step });
+                }
+                continue;
+            }
+            if !self.sized(step) {
+                return Some(Problem::Gap { at: i, step });
+            }
+            if sign == 0 {
+                sign = step.signum();
+            } else if step.signum() != sign {
+                return Some(Problem::Flip { at: i });
+            }
+        }
+        return None;
+    }
+}
+
+// removals finds the fewest levels (at most k) to remove so the report goes in the direction of sign.
+// fewest[i] is the fewest removals before level i when it's kept, it only depends on the
+// k + 1 levels before it, as skipping more would already be too many removals.
+fn removals(r: &Vec<i32>, k: usize, rules: &Rules, sign: i32) -> Option<Vec<usize>> {
+    let n = r.len();
+    if n == 0 {
+        return Some(Vec::new());
+    }
+    let mut fewest = vec![usize::MAX; n];
+    let mut prev: Vec<Option<usize>> = vec![None; n];
+    for i in 0..n {
+        // all the levels before the first kept one are removed
+        if i <= k {
+            fewest[i] = i;
+        }
+        for j in i.saturating_sub(k + 1)..i {
+            if fewest[j] == usize::MAX || !rules.fits(r[j], r[i], sign) {
+                continue;
+            }
+            let cost = fewest[j] + (i - j - 1);
+            if cost <= k && cost < fewest[i] {
+                fewest[i] = cost;
+                prev[i] = Some(j);
+            }
+        }
+    }
+    // so are the levels after the last kept one
+    let last = (0..n)
+        .filter(|&i| fewest[i] != usize::MAX && fewest[i] + (n - 1 - i) <= k)
+        .min_by_key(|&i| fewest[i] + (n - 1 - i))?;
+    let mut kept = vec![false; n];
+    let mut i = Some(last);
+    while let Some(j) = i {
+        kept[j] = true;
+        i = prev[j];
+    }
+    return Some((0..n).filter(|&i| !kept[i]).collect());
+}
+
+// diagnose checks a report against the rules allowing up to k levels to be removed
+pub fn diagnose(r: &Vec<i32>, k: usize, rules: &Rules) -> Diagnosis {
+    let problem = rules.problem(r);
+    if problem.is_none() {
+        return Diagnosis {
+            problem,
+            removed: Some(Vec::new()),
+        };
+    }
+    let removed = match (removals(r, k, rules, 1), removals(r, k, rules, -1)) {
+        (Some(up), Some(down)) if down.len() < up.len() => Some(down),
+        (None, down) => down,
+        (up, _) => up,
+    };
+    return Diagnosis { problem, removed };
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(txt: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(Self::DAY, txt)
+            .map(|line| {
+                if line.text.trim().is_empty() {
+                    return Err(line.error_at_end("a report"));
+                }
+                line.text
+                    .split_ascii_whitespace()
+                    .map(|x| line.number(x))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .filter(|r| diagnose(r, 0, &Rules::PUZZLE).safe())
+            .count()
+            .into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .filter(|r| diagnose(r, 1, &Rules::PUZZLE).safe())
+            .count()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use rand::Rng;
+
+    use super::*;
+    use crate::differential::{cross_check, shrink_vec};
+
+    #[test]
+    fn check() {
+        let reports = Day2::parse(include_str!("test_input.txt")).unwrap();
+        let diagnoses: Vec<Diagnosis> = reports
+            .iter()
+            .map(|r| diagnose(r, 1, &Rules::PUZZLE))
+            .collect();
+        let expected = [
+            (None, Some(vec![])),
+            (Some(Problem::Gap { at: 2, step: 5 }), None),
+            (Some(Problem::Gap { at: 3, step: -4 }), None),
+            (Some(Problem::Flip { at: 2 }), Some(vec![2])),
+            (Some(Problem::Gap { at: 3, step: 0 }), Some(vec![3])),
+            (None, Some(vec![])),
+        ];
+        for (d, (problem, removed)) in diagnoses.iter().zip(expected) {
+            assert_eq!(&Diagnosis { problem, removed }, d);
+        }
+        assert_eq!(Answer::Num(2), Day2::part1(&reports));
+        assert_eq!(Answer::Num(4), Day2::part2(&reports));
+        assert_eq!(
+            Some(vec![1, 2]),
+            diagnose(&vec![1, 9, 8, 2, 3], 2, &Rules::PUZZLE).removed
+        );
+        assert_eq!(
+            None,
+            diagnose(&vec![1, 9, 8, 2, 3], 1, &Rules::PUZZLE).removed
+        );
+    }
+
+    // fewest_removals tries every combination of removed levels, smallest first
+    fn fewest_removals(r: &Vec<i32>, k: usize, rules: &Rules) -> Option<usize> {
+        for removed in 0..=k.min(r.len()) {
+            for skip in (0..r.len()).combinations(removed) {
+                let t: Vec<i32> = (0..r.len())
+                    .filter(|i| !skip.contains(i))
+                    .map(|i| r[i])
+                    .collect();
+                if rules.problem(&t).is_none() {
+                    return Some(removed);
+                }
+            }
+        }
+        return None;
+    }
+
+    #[test]
+    fn check_rules() {
+        let plateaus = Rules::new(2, 4, Monotonic::NonStrict, true).unwrap();
+        let r = vec![1, 1, 3, 3, 7, 4];
+        assert_eq!(
+            Some(Problem::Gap { at: 1, step: 0 }),
+            Rules::PUZZLE.problem(&r)
+        );
+        assert_eq!(Some(Problem::Flip { at: 5 }), plateaus.problem(&r));
+        assert_eq!(Some(vec![5]), diagnose(&r, 1, &plateaus).removed);
+        let strict = Rules::new(0, 2, Monotonic::Strict, false).unwrap();
+        assert_eq!(Some(Problem::Gap { at: 1, step: 0 }), strict.problem(&r));
+        let flat = Rules::new(0, 4, Monotonic::NonStrict, false).unwrap();
+        assert_eq!(Some(Problem::Flip { at: 5 }), flat.problem(&r));
+        let r = vec![i32::MAX, i32::MIN];
+        assert_eq!(
+            Some(Problem::Gap {
+                at: 1,
+                step: i64::from(i32::MIN) - i64::from(i32::MAX)
+            }),
+            Rules::PUZZLE.problem(&r)
+        );
+        assert_eq!(
+            Some(1),
+            diagnose(&r, 1, &Rules::PUZZLE).removed.map(|v| v.len())
+        );
+        assert_eq!(None, diagnose(&r, 0, &Rules::PUZZLE).removed);
+        assert_eq!(
+            Some(vec![1, 3]),
+            diagnose(&vec![1, i32::MIN, 2, i32::MAX], 2, &Rules::PUZZLE).removed
+        );
+        assert!(Rules::new(3, 1, Monotonic::NonStrict, false).is_err());
+        assert!(Rules::new(1, 3, Monotonic::Strict, true).is_err());
+        assert_eq!(Ok(Monotonic::NonStrict), "non-strict".parse());
+    }
+
+    #[test]
+    fn check_removals_vs_combinations() {
+        let rules = [
+            Rules::PUZZLE,
+            Rules::new(2, 4, Monotonic::NonStrict, true).unwrap(),
+            Rules::new(0, 1, Monotonic::NonStrict, false).unwrap(),
+        ];
+        for (k, rules) in (0..=3).cartesian_product(rules) {
+            let r = cross_check(
+                500,
+                k as u64,
+                |rng| {
+                    let mut level = rng.gen_range(0..20);
+                    (0..rng.gen_range(1..10))
+                        .map(|_| {
+                            level += rng.gen_range(-4..=4);
+                            level
+                        })
+                        .collect::<Vec<i32>>()
+                },
+                shrink_vec,
+                |r| fewest_removals(r, k, &rules),
+                |r| diagnose(r, k, &rules).removed.map(|removed| removed.len()),
+            );
+            r.unwrap_or_else(|m| panic!("k = {k}, {rules:?}: {m}"));
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use pathfinding::prelude::astar;
+use rayon::prelude::*;
+
+use crate::{
+    grid::Grid,
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
+pub type Point = (i32, i32);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    Wall,
+    Start,
+    End,
+}
+
+impl From<char> for Cell {
+    fn from(val: char) -> Self {
+        match val {
+            '#' => Cell::Wall,
+            'S' => Cell::Start,
+            'E' => Cell::End,
+            _ => Cell::Empty,
+        }
+    }
+}
+
+fn load_input(txt: &str) -> Result<(Grid<Cell>, Point, Point), ParseError> {
+    let map = Grid::parse(Day20::DAY, txt, "a track cell", |c| {
+        "#.SE".contains(c).then(|| Cell::from(c))
+    })?;
+    match (
+        map.find(|c| *c == Cell::Start),
+        map.find(|c| *c == Cell::End),
+    ) {
+        (None, _) => Err(parse::missing(Day20::DAY, txt, "a start `S`")),
+        (_, None) => Err(parse::missing(Day20::DAY, txt, "an end `E`")),
+        (Some(start_pos), Some(end_pos)) => Ok((map, start_pos, end_pos)),
+    }
+}
+
+fn get_points(
+    map: &Grid<Cell>,
+    around: &Point,
+    shortcut: &Option<(Point, Point)>,
+) -> Vec<(Point, i32)> {
+    let mut adj: Vec<(Point, i32)> = map
+        .neighbours4(*around)
+        .filter(|(_, x)| map[*x] != Cell::Wall)
+        .map(|(_, p)| (p, 1))
+        .collect();
+    if let Some((start, end)) = shortcut {
+        if *start == *around && map[*end] != Cell::Wall {
+            adj.push((*end, get_dist(start, end)));
+        }
+    }
+    return adj;
+}
+
+fn find_path(
+    map: &Grid<Cell>,
+    start: &Point,
+    end: &Point,
+    shortcut: &Option<(Point, Point)>,
+) -> Option<(Vec<Point>, i32)> {
+    astar(
+        start,
+        |point| get_points(map, point, shortcut),
+        |point| (end.0 - point.0).abs() + (end.1 - point.1).abs(),
+        |point| *point == *end,
+    )
+}
+
+fn get_dist(a: &Point, b: &Point) -> i32 {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
+
+// get_shortcuts pairs every track cell inside of the outer wall with all track cells within max_dist
+fn get_shortcuts(map: &Grid<Cell>, max_dist: i32) -> Vec<(Point, Point)> {
+    let (h, w) = (map.height() as i32, map.width() as i32);
+    let inner: Vec<Point> = map
+        .iter()
+        .filter(|((r, c), cell)| {
+            *r > 0 && *r < h - 1 && *c > 0 && *c < w - 1 && **cell != Cell::Wall
+        })
+        .map(|(p, _)| p)
+        .collect();
+    let mut shortcuts = Vec::new();
+    for start in &inner {
+        if map[*start] == Cell::End {
+            continue;
+        }
+        for end in &inner {
+            if get_dist(start, end) <= max_dist {
+                shortcuts.push((*start, *end));
+            }
+        }
+    }
+    return shortcuts;
+}
+
+fn find_with_shortcuts(
+    map: &Grid<Cell>,
+    start: &Point,
+    end: &Point,
+    max_shortcut: i32,
+) -> Vec<(i32, (Point, Point))> {
+    let shortcuts = get_shortcuts(map, max_shortcut);
+
+    let scores = shortcuts
+        .into_par_iter()
+        .map(|shortcut| {
+            if let Some((_path, score)) = find_path(map, start, end, &Some(shortcut)) {
+                Some((score, shortcut))
+            } else {
+                None
+            }
+        })
+        .filter(Option::is_some)
+        .map(Option::unwrap)
+        .collect();
+
+    return scores;
+}
+
+// count_cheats counts shortcuts of up to max_shortcut steps that save at least min_saving,
+// None when there is no track from start to end to cheat on
+fn count_cheats(
+    map: &Grid<Cell>,
+    start: &Point,
+    end: &Point,
+    max_shortcut: i32,
+    min_saving: i32,
+) -> Option<usize> {
+    let (_path, base_score) = find_path(map, start, end, &None)?;
+    let scores = find_with_shortcuts(map, start, end, max_shortcut);
+    let count = scores
+        .iter()
+        .filter(|(score, _)| base_score - *score >= min_saving)
+        .count();
+    return Some(count);
+}
+
+const MIN_SAVING: i32 = 100;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Input = (Grid<Cell>, Point, Point);
+
+    fn parse(txt: &str) -> Result<Self::Input, ParseError> {
+        load_input(txt)
+    }
+
+    fn part1((map, start, end): &Self::Input) -> Answer {
+        match count_cheats(map, start, end, 2, MIN_SAVING) {
+            Some(count) => count.into(),
+            None => Answer::Unsolved,
+        }
+    }
+
+    fn part2((map, start, end): &Self::Input) -> Answer {
+        match count_cheats(map, start, end, 20, MIN_SAVING) {
+            Some(count) => count.into(),
+            None => Answer::Unsolved,
+        }
+    }
+
+    fn diagnostics((map, start, end): &Self::Input, part: u8) -> Vec<(&'static str, String)> {
+        let max_shortcut = if part == 1 { 2 } else { 20 };
+        let mut d = vec![
+            ("max cheat length", max_shortcut.to_string()),
+            ("min saving", MIN_SAVING.to_string()),
+        ];
+        if find_path(map, start, end, &None).is_none() {
+            d.push(("track", "walls cut the end off from the start".to_string()));
+        }
+        return d;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check1() {
+        let input = include_str!("test_input.txt");
+        let (map, start, end) = load_input(input).unwrap();
+        assert_eq!(Some(44), count_cheats(&map, &start, &end, 2, 1));
+        assert_eq!(Some(5), count_cheats(&map, &start, &end, 2, 20));
+        assert_eq!(Some(285), count_cheats(&map, &start, &end, 20, 50));
+    }
+
+    #[test]
+    fn check_no_track() {
+        let input = Day20::parse("#####\n#S#E#\n#####").unwrap();
+        assert_eq!(Answer::Unsolved, Day20::part1(&input));
+        let d = Day20::diagnostics(&input, 1);
+        assert_eq!(
+            ("track", "walls cut the end off from the start".to_string()),
+            d[2]
+        );
+    }
+}
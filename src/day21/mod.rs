@@ -1,7 +1,12 @@
-use std::iter::repeat;
+use std::iter::repeat_n;
 
 use itertools::Itertools;
 
+use crate::{
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
 type Point = (i32, i32);
 
 const NUMPAD: &'static [&'static [char]] = &[
@@ -78,12 +83,10 @@ fn route_numpad(source: &char, target: &char) -> Vec<Vec<char>> {
     let (src, tgt) = (coord(source, NUMPAD), coord(target, NUMPAD));
     let (dr, dc) = (tgt.0 - src.0, tgt.1 - src.1);
 
-    let hor: Vec<char> = repeat(if dc < 0 { '<' } else { '>' })
-        .take(dc.abs() as usize)
-        .collect();
-    let vert: Vec<char> = repeat(if dr < 0 { '^' } else { 'v' })
-        .take(dr.abs() as usize)
-        .collect();
+    let hor: Vec<char> =
+        repeat_n(if dc < 0 { '<' } else { '>' }, dc.unsigned_abs() as usize).collect();
+    let vert: Vec<char> =
+        repeat_n(if dr < 0 { '^' } else { 'v' }, dr.unsigned_abs() as usize).collect();
 
     let only_hor_first = ['7', '4', '1'].contains(source) && ['0', 'A'].contains(target);
     let only_vert_first = ['0', 'A'].contains(source) && ['7', '4', '1'].contains(target);
@@ -107,7 +110,7 @@ fn route_numpad(source: &char, target: &char) -> Vec<Vec<char>> {
 // dial_num returns input for the directional control attached to numpad robot
 fn dial_num(num: char, start: char) -> Vec<String> {
     let mut numpad_inputs = route_numpad(&start, &num);
-    if numpad_inputs.len() == 0 {
+    if numpad_inputs.is_empty() {
         return vec![String::from("A")];
     }
     for input in &mut numpad_inputs {
@@ -122,7 +125,7 @@ fn dial_num(num: char, start: char) -> Vec<String> {
 // dial_arr returns input for the directional control attached to another directional control
 fn dial_arr(arr: char, start: char) -> Vec<String> {
     let mut inputs = route_arr(&start, &arr);
-    if inputs.len() == 0 {
+    if inputs.is_empty() {
         return vec![String::from("A")];
     }
     for input in &mut inputs {
@@ -195,23 +198,45 @@ fn dial_code_via_proxies(code: &str, proxies: usize) -> usize {
     return size;
 }
 
-fn load_input(txt: &str) -> Vec<String> {
-    txt.split("\n").map(String::from).collect()
+fn load_input(txt: &str) -> Result<Vec<String>, ParseError> {
+    parse::lines(Day21::DAY, txt)
+        .map(|l| match l.text.strip_suffix('A') {
+            Some(num) if !num.is_empty() && num.chars().all(|c| c.is_ascii_digit()) => {
+                Ok(l.text.to_string())
+            }
+            _ => Err(l.error(l.text, "a door code like `029A`")),
+        })
+        .collect()
 }
 
-fn main() {
-    let input = load_input(include_str!("input.txt"));
-    let codes: Vec<_> = input.iter().map(|x| dial_code_via_proxies(x, 2)).collect();
-    let mut complexity = 0;
-    for i in 0..input.len() {
-        complexity += input[i]
-            .strip_suffix("A")
-            .unwrap()
-            .parse::<usize>()
-            .unwrap()
-            * codes[i]
-    }
-    println!("part1: {complexity}");
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    type Input = Vec<String>;
+
+    fn parse(txt: &str) -> Result<Self::Input, ParseError> {
+        load_input(txt)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let codes: Vec<_> = input.iter().map(|x| dial_code_via_proxies(x, 2)).collect();
+        let mut complexity = 0;
+        for i in 0..input.len() {
+            complexity += input[i]
+                .strip_suffix("A")
+                .unwrap()
+                .parse::<usize>()
+                .unwrap()
+                * codes[i]
+        }
+        complexity.into()
+    }
+
+    fn part2(_input: &Self::Input) -> Answer {
+        // brute force does not scale to 25 robots
+        Answer::Unsolved
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,455 @@
+use std::{
+    fmt,
+    io::{self, Read},
+};
+
+use console::style;
+
+use crate::{parse::ParseError, Answer, Solution};
+
+// Op is an instruction found in the corrupted memory
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Mul(i64, i64),
+    Do,
+    Dont,
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Op::Mul(a, b) => write!(f, "mul({a},{b})"),
+            Op::Do => write!(f, "do()"),
+            Op::Dont => write!(f, "don't()"),
+        }
+    }
+}
+
+// Spelling tells how an instruction is written: its name followed by
+// parenthesised, comma separated arguments of 1 to 3 digits
+struct Spelling {
+    name: &'static str,
+    args: usize,
+    op: fn(&[i64]) -> Op,
+}
+
+// SPELLINGS are all the instructions the lexer knows, a new one only needs a line here
+const SPELLINGS: [Spelling; 3] = [
+    Spelling {
+        name: "mul",
+        args: 2,
+        op: |args| Op::Mul(args[0], args[1]),
+    },
+    Spelling {
+        name: "do",
+        args: 0,
+        op: |_| Op::Do,
+    },
+    Spelling {
+        name: "don't",
+        args: 0,
+        op: |_| Op::Dont,
+    },
+];
+
+const MAX_DIGITS: usize = 3;
+// MAX_ARGS bounds the arguments of any spelling, so they fit on the stack
+const MAX_ARGS: usize = 2;
+// MAX_BREAKS bounds the line break bytes within one instruction (two `\r\n`), so a long
+// run of them can't make the scanner keep an unfinished instruction forever
+const MAX_BREAKS: usize = 4;
+
+// Lexed is what starts at some position of the memory
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lexed {
+    // an instruction spanning the given number of bytes
+    Op(Op, usize),
+    // garbage
+    Nothing,
+    // the memory ends in the middle of what may still become an instruction
+    Incomplete,
+}
+
+// peek returns the byte at i skipping line breaks, an instruction may be split across lines
+// as long as it starts on the first byte of txt; breaks counts the bytes skipped so far
+fn peek(txt: &[u8], i: &mut usize, breaks: &mut usize) -> Option<u8> {
+    while *i > 0 && *breaks < MAX_BREAKS && matches!(txt.get(*i), Some(b'\r' | b'\n')) {
+        *i += 1;
+        *breaks += 1;
+    }
+    return txt.get(*i).copied();
+}
+
+// spelled reads the instruction of the given spelling at the start of txt
+fn spelled(s: &Spelling, txt: &[u8]) -> Lexed {
+    let (mut i, mut breaks) = (0, 0);
+    let mut args = [0i64; MAX_ARGS];
+    for &c in s.name.as_bytes().iter().chain(b"(") {
+        match peek(txt, &mut i, &mut breaks) {
+            None => return Lexed::Incomplete,
+            Some(b) if b == c => i += 1,
+            Some(_) => return Lexed::Nothing,
+        }
+    }
+    for a in 0..s.args {
+        if a > 0 {
+            match peek(txt, &mut i, &mut breaks) {
+                None => return Lexed::Incomplete,
+                Some(b',') => i += 1,
+                Some(_) => return Lexed::Nothing,
+            }
+        }
+        let (mut n, mut digits) = (0, 0);
+        while digits < MAX_DIGITS {
+            match peek(txt, &mut i, &mut breaks) {
+                None => return Lexed::Incomplete,
+                Some(b) if b.is_ascii_digit() => {
+                    n = n * 10 + (b - b'0') as i64;
+                    digits += 1;
+                    i += 1;
+                }
+                Some(_) => break,
+            }
+        }
+        if digits == 0 {
+            return Lexed::Nothing;
+        }
+        args[a] = n;
+    }
+    match peek(txt, &mut i, &mut breaks) {
+        None => return Lexed::Incomplete,
+        Some(b')') => return Lexed::Op((s.op)(&args[..s.args]), i + 1),
+        Some(_) => return Lexed::Nothing,
+    }
+}
+
+// lex_at reads the instruction at the start of txt, if any
+pub fn lex_at(txt: &[u8]) -> Lexed {
+    let mut lexed = Lexed::Nothing;
+    for s in &SPELLINGS {
+        match spelled(s, txt) {
+            Lexed::Op(op, len) => return Lexed::Op(op, len),
+            Lexed::Incomplete => lexed = Lexed::Incomplete,
+            Lexed::Nothing => {}
+        }
+    }
+    return lexed;
+}
+
+// Lexer yields the instructions of the memory with their byte offsets, in a single pass:
+// every attempt looks at most at a few bytes ahead
+pub struct Lexer<'a> {
+    txt: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(txt: &'a [u8]) -> Self {
+        Lexer { txt, pos: 0 }
+    }
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = (usize, Op);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pos < self.txt.len() {
+            if let Lexed::Op(op, len) = lex_at(&self.txt[self.pos..]) {
+                let at = self.pos;
+                self.pos += len;
+                return Some((at, op));
+            }
+            self.pos += 1;
+        }
+        None
+    }
+}
+
+pub const CHUNK: usize = 64 * 1024;
+
+// Scanner lexes memory from a reader chunk by chunk, an instruction may span chunks:
+// only the bytes that can still become one are kept for the next chunk,
+// so the memory used doesn't grow with the input
+pub struct Scanner<R: Read> {
+    reader: R,
+    buf: Vec<u8>,
+    chunk: usize,
+    // position of the next byte to lex in buf
+    pos: usize,
+    // offset of buf[0] in the whole memory
+    offset: usize,
+    eof: bool,
+}
+
+impl<R: Read> Scanner<R> {
+    pub fn new(reader: R, chunk: usize) -> Self {
+        Scanner {
+            reader,
+            buf: Vec::with_capacity(chunk),
+            chunk: chunk.max(1),
+            pos: 0,
+            offset: 0,
+            eof: false,
+        }
+    }
+
+    // refill drops the lexed bytes and appends the next chunk
+    fn refill(&mut self) -> io::Result<()> {
+        self.buf.drain(..self.pos);
+        self.offset += self.pos;
+        self.pos = 0;
+        let len = self.buf.len();
+        self.buf.resize(len + self.chunk, 0);
+        let read = loop {
+            match self.reader.read(&mut self.buf[len..]) {
+                Ok(n) => break n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    self.buf.truncate(len);
+                    return Err(e);
+                }
+            }
+        };
+        self.buf.truncate(len + read);
+        if read == 0 {
+            self.eof = true;
+        }
+        return Ok(());
+    }
+}
+
+impl<R: Read> Iterator for Scanner<R> {
+    type Item = io::Result<(usize, Op)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.pos == self.buf.len() && self.eof {
+                return None;
+            }
+            match lex_at(&self.buf[self.pos..]) {
+                Lexed::Op(op, len) => {
+                    let at = self.offset + self.pos;
+                    self.pos += len;
+                    return Some(Ok((at, op)));
+                }
+                Lexed::Incomplete if !self.eof => {
+                    if let Err(e) = self.refill() {
+                        return Some(Err(e));
+                    }
+                }
+                _ => self.pos += 1,
+            }
+        }
+    }
+}
+
+// Machine evaluates instructions, with conditionals don't() disables the muls until the next do()
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Machine {
+    pub conditionals: bool,
+    pub enabled: bool,
+    pub total: i64,
+}
+
+impl Machine {
+    pub fn new(conditionals: bool) -> Self {
+        Machine {
+            conditionals,
+            enabled: true,
+            total: 0,
+        }
+    }
+
+    // run executes one instruction and tells whether it was a mul that counted
+    pub fn run(&mut self, op: Op) -> bool {
+        match op {
+            Op::Mul(a, b) if self.enabled => {
+                self.total += a * b;
+                return true;
+            }
+            Op::Mul(_, _) => return false,
+            Op::Do => self.enabled = true,
+            Op::Dont => self.enabled = !self.conditionals,
+        }
+        return false;
+    }
+}
+
+// Step is one instruction of a trace with the state of the machine after running it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub offset: usize,
+    pub op: Op,
+    pub enabled: bool,
+    pub counted: bool,
+    pub total: i64,
+}
+
+impl Machine {
+    // step runs an instruction found at offset and records it
+    pub fn step(&mut self, offset: usize, op: Op) -> Step {
+        let counted = self.run(op);
+        return Step {
+            offset,
+            op,
+            enabled: self.enabled,
+            counted,
+            total: self.total,
+        };
+    }
+}
+
+// highlight styles the memory for the console: counted muls green, skipped ones red,
+// do() and don't() yellow and everything disabled dimmed
+pub fn highlight(txt: &str) -> String {
+    let mut machine = Machine::new(true);
+    let mut out = String::new();
+    let mut pos = 0;
+    for (offset, op) in Lexer::new(txt.as_bytes()) {
+        let garbage = &txt[pos..offset];
+        if machine.enabled {
+            out.push_str(garbage);
+        } else {
+            out.push_str(&style(garbage).dim().to_string());
+        }
+        // the op may be written with leading zeros, so its text is taken as is
+        pos = match lex_at(&txt.as_bytes()[offset..]) {
+            Lexed::Op(_, len) => offset + len,
+            _ => unreachable!("the lexer found {op} at {offset}"),
+        };
+        let text = &txt[offset..pos];
+        let step = machine.step(offset, op);
+        let styled = match op {
+            Op::Mul(_, _) if step.counted => style(text).green().bold(),
+            Op::Mul(_, _) => style(text).red(),
+            Op::Do | Op::Dont => style(text).yellow(),
+        };
+        out.push_str(&styled.to_string());
+    }
+    if machine.enabled {
+        out.push_str(&txt[pos..]);
+    } else {
+        out.push_str(&style(&txt[pos..]).dim().to_string());
+    }
+    return out;
+}
+
+// evaluate runs all the instructions and returns the sum of the counted muls
+fn evaluate(ops: &Vec<(usize, Op)>, conditionals: bool) -> i64 {
+    let mut machine = Machine::new(conditionals);
+    for (_, op) in ops {
+        machine.run(*op);
+    }
+    return machine.total;
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input = Vec<(usize, Op)>;
+
+    fn parse(txt: &str) -> Result<Self::Input, ParseError> {
+        Ok(Lexer::new(txt.as_bytes()).collect())
+    }
+
+    fn part1(ops: &Self::Input) -> Answer {
+        evaluate(ops, false).into()
+    }
+
+    fn part2(ops: &Self::Input) -> Answer {
+        evaluate(ops, true).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check() {
+        let ops = Day3::parse(include_str!("test_input.txt")).unwrap();
+        assert_eq!(161, evaluate(&ops, false));
+        let ops = Day3::parse(include_str!("test_input2.txt")).unwrap();
+        assert_eq!(161, evaluate(&ops, false));
+        assert_eq!(48, evaluate(&ops, true));
+    }
+
+    #[test]
+    fn check_lexer() {
+        let ops: Vec<(usize, Op)> =
+            Lexer::new(b"mumul(2,3)mul(1234,5)mul( 1,2)don't()do()mul(7,8,9)mul(999,0)").collect();
+        assert_eq!(
+            vec![
+                (2, Op::Mul(2, 3)),
+                (30, Op::Dont),
+                (37, Op::Do),
+                (51, Op::Mul(999, 0))
+            ],
+            ops
+        );
+        assert_eq!(Lexed::Incomplete, lex_at(b"mul(12"));
+        assert_eq!(Lexed::Incomplete, lex_at(b"do"));
+        assert_eq!(Lexed::Nothing, lex_at(b"mul(1,x"));
+        assert_eq!(Lexed::Op(Op::Dont, 7), lex_at(b"don't()"));
+    }
+
+    #[test]
+    fn check_trace() {
+        let txt = include_str!("test_input2.txt");
+        let mut machine = Machine::new(true);
+        let steps: Vec<Step> = Lexer::new(txt.as_bytes())
+            .map(|(offset, op)| machine.step(offset, op))
+            .collect();
+        let trace: Vec<(usize, String, bool, i64)> = steps
+            .iter()
+            .map(|s| (s.offset, s.op.to_string(), s.counted, s.total))
+            .collect();
+        let expected = [
+            (1, "mul(2,4)", true, 8),
+            (20, "don't()", false, 8),
+            (28, "mul(5,5)", false, 8),
+            (48, "mul(11,8)", false, 8),
+            (59, "do()", false, 8),
+            (64, "mul(8,5)", true, 48),
+        ];
+        assert_eq!(
+            expected
+                .map(|(o, op, c, t)| (o, op.to_string(), c, t))
+                .to_vec(),
+            trace
+        );
+        assert!(!steps[1].enabled && steps[4].enabled);
+        console::set_colors_enabled(false);
+        assert_eq!(txt, highlight(txt));
+        assert_eq!("xmul(01,2)", highlight("xmul(01,2)"));
+    }
+
+    #[test]
+    fn check_scanner() {
+        let txt = include_str!("test_input2.txt").repeat(3) + "mul(1,\n2)do(\n)mul(3,4)";
+        let lexed: Vec<(usize, Op)> = Lexer::new(txt.as_bytes()).collect();
+        assert_eq!(21, lexed.len());
+        // instructions broken by a line break still count
+        assert_eq!(
+            vec![Op::Mul(1, 2), Op::Do, Op::Mul(3, 4)],
+            lexed[18..].iter().map(|(_, op)| *op).collect::<Vec<Op>>()
+        );
+        assert_eq!(Lexed::Op(Op::Mul(2, 3), 10), lex_at(b"mul(2,\r\n3)"));
+        assert_eq!(Lexed::Incomplete, lex_at(b"mul(2,\n"));
+        assert_eq!(Lexed::Nothing, lex_at(b"\nmul(2,3)"));
+        assert_eq!(Lexed::Op(Op::Do, 8), lex_at(b"do(\r\n\r\n)"));
+        assert_eq!(Lexed::Nothing, lex_at(b"do(\r\n\r\n\n)"));
+        for chunk in [1, 2, 3, 7, 100] {
+            let scanned: Vec<(usize, Op)> = Scanner::new(txt.as_bytes(), chunk)
+                .map(|r| r.unwrap())
+                .collect();
+            assert_eq!(lexed, scanned, "chunk of {chunk}");
+        }
+        // an instruction left open by a long run of line breaks isn't kept around
+        let txt = "mul(".to_string() + &"\r\n".repeat(10000) + "mul(3,4)";
+        let mut scanner = Scanner::new(txt.as_bytes(), 16);
+        assert_eq!(20004, scanner.next().unwrap().unwrap().0);
+        assert!(scanner.buf.len() <= 32, "{} bytes kept", scanner.buf.len());
+    }
+}
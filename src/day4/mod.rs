@@ -0,0 +1,510 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    str::FromStr,
+};
+
+use console::style;
+
+use crate::{
+    grid::{Grid, Pos},
+    parse::ParseError,
+    Answer, Solution,
+};
+
+// Match is an occurrence of words[word] starting at start and going in direction dir
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Match {
+    pub word: usize,
+    pub start: Pos,
+    pub dir: Pos,
+}
+
+impl Match {
+    // cells are the positions of the letters of a word of the given length
+    pub fn cells(&self, len: usize) -> impl Iterator<Item = Pos> + '_ {
+        (0..len as i32).map(|i| (self.start.0 + i * self.dir.0, self.start.1 + i * self.dir.1))
+    }
+}
+
+// direction names a step between neighbouring cells
+pub fn direction(dir: Pos) -> &'static str {
+    match dir {
+        (-1, 0) => "up",
+        (-1, 1) => "up-right",
+        (0, 1) => "right",
+        (1, 1) => "down-right",
+        (1, 0) => "down",
+        (1, -1) => "down-left",
+        (0, -1) => "left",
+        (-1, -1) => "up-left",
+        _ => "nowhere",
+    }
+}
+
+const NONE: usize = usize::MAX;
+
+// Automaton is an Aho-Corasick automaton: a trie of the patterns where every node also
+// knows the node of the longest proper suffix of its path (fail). Missing transitions
+// are filled in from the fail nodes, so a text is scanned once, one table lookup per
+// letter, for all the patterns whatever their number and length.
+pub struct Automaton {
+    // symbol of every letter of the patterns, ASCII ones without hashing
+    ascii: [usize; 128],
+    other: HashMap<char, usize>,
+    symbols: usize,
+    // next[node * symbols + symbol]
+    next: Vec<usize>,
+    // patterns ending at the node, including the ones ending at its fail nodes
+    out: Vec<Vec<usize>>,
+}
+
+impl Automaton {
+    pub fn new(patterns: &Vec<Vec<char>>) -> Self {
+        let mut a = Automaton {
+            ascii: [NONE; 128],
+            other: HashMap::new(),
+            symbols: 0,
+            next: Vec::new(),
+            out: vec![Vec::new()],
+        };
+        for c in patterns.iter().flatten() {
+            if a.symbol(*c).is_none() {
+                if c.is_ascii() {
+                    a.ascii[*c as usize] = a.symbols;
+                } else {
+                    a.other.insert(*c, a.symbols);
+                }
+                a.symbols += 1;
+            }
+        }
+        let n = a.symbols;
+        a.next = vec![NONE; n];
+        for (i, pattern) in patterns.iter().enumerate() {
+            // the empty pattern would be found everywhere
+            if pattern.is_empty() {
+                continue;
+            }
+            let mut node = 0;
+            for c in pattern {
+                let s = a.symbol(*c).unwrap();
+                if a.next[node * n + s] == NONE {
+                    a.next[node * n + s] = a.out.len();
+                    a.next.extend(std::iter::repeat_n(NONE, n));
+                    a.out.push(Vec::new());
+                }
+                node = a.next[node * n + s];
+            }
+            a.out[node].push(i);
+        }
+        // nodes are completed by depth, so their fail nodes are complete already
+        let mut fail = vec![0; a.out.len()];
+        let mut queue = VecDeque::from([0]);
+        while let Some(node) = queue.pop_front() {
+            for s in 0..n {
+                let child = a.next[node * n + s];
+                let link = if node == 0 {
+                    0
+                } else {
+                    a.next[fail[node] * n + s]
+                };
+                if child == NONE {
+                    a.next[node * n + s] = link;
+                    continue;
+                }
+                fail[child] = link;
+                let inherited = a.out[link].clone();
+                a.out[child].extend(inherited);
+                queue.push_back(child);
+            }
+        }
+        return a;
+    }
+
+    fn symbol(&self, c: char) -> Option<usize> {
+        let s = match c.is_ascii() {
+            true => self.ascii[c as usize],
+            false => *self.other.get(&c)?,
+        };
+        return if s == NONE { None } else { Some(s) };
+    }
+
+    // step follows the letter c from node, letters of no pattern start over
+    pub fn step(&self, node: usize, c: char) -> usize {
+        match self.symbol(c) {
+            Some(s) => self.next[node * self.symbols + s],
+            None => 0,
+        }
+    }
+
+    // found are the patterns ending at node
+    pub fn found(&self, node: usize) -> &Vec<usize> {
+        &self.out[node]
+    }
+}
+
+// lines are the first cell and direction of every row, column and diagonal of the grid
+fn lines(width: i32, height: i32) -> Vec<(Pos, Pos)> {
+    let mut lines = Vec::new();
+    for r in 0..height {
+        lines.push(((r, 0), (0, 1)));
+        lines.push(((r, 0), (1, 1)));
+        lines.push(((r, width - 1), (1, -1)));
+    }
+    for c in 0..width {
+        lines.push(((0, c), (1, 0)));
+        if c > 0 {
+            lines.push(((0, c), (1, 1)));
+        }
+        if c < width - 1 {
+            lines.push(((0, c), (1, -1)));
+        }
+    }
+    return lines;
+}
+
+// find_words finds all the words in all 8 directions, a single letter only once (going right).
+// Every line of the grid is scanned once forwards for the words and their reverses,
+// a reversed word found going one way is the word going the other way.
+pub fn find_words(grid: &Grid<char>, words: &Vec<String>) -> Vec<Match> {
+    // patterns are the words followed by their reverses
+    let mut patterns: Vec<Vec<char>> = words.iter().map(|w| w.chars().collect()).collect();
+    for w in words {
+        patterns.push(w.chars().rev().collect());
+    }
+    let automaton = Automaton::new(&patterns);
+    let mut matches = Vec::new();
+    for (first, dir) in lines(grid.width() as i32, grid.height() as i32) {
+        let mut node = 0;
+        let mut p = first;
+        while let Some(c) = grid.get(p) {
+            node = automaton.step(node, *c);
+            for &i in automaton.found(node) {
+                let (word, reversed) = (i % words.len(), i >= words.len());
+                let len = patterns[i].len() as i32;
+                if len == 1 && (reversed || dir != (0, 1)) {
+                    continue;
+                }
+                let begin = (p.0 - (len - 1) * dir.0, p.1 - (len - 1) * dir.1);
+                matches.push(match reversed {
+                    false => Match {
+                        word,
+                        start: begin,
+                        dir,
+                    },
+                    true => Match {
+                        word,
+                        start: p,
+                        dir: (-dir.0, -dir.1),
+                    },
+                });
+            }
+            p = (p.0 + dir.0, p.1 + dir.1);
+        }
+    }
+    matches.sort();
+    return matches;
+}
+
+// Mask is a 2D shape of letters, like the X of two MAS, written with `.` for any letter.
+// It is looked for in all its rotations and reflections.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mask {
+    // the distinct rotations and reflections, each as (offset from the top left, letter)
+    pub variants: Vec<Vec<(Pos, char)>>,
+}
+
+// normalized moves the cells to the top left corner and sorts them
+fn normalized(cells: &Vec<(Pos, char)>) -> Vec<(Pos, char)> {
+    let top = cells.iter().map(|((r, _), _)| *r).min().unwrap_or(0);
+    let left = cells.iter().map(|((_, c), _)| *c).min().unwrap_or(0);
+    let mut moved: Vec<(Pos, char)> = cells
+        .iter()
+        .map(|((r, c), l)| ((r - top, c - left), *l))
+        .collect();
+    moved.sort();
+    return moved;
+}
+
+impl FromStr for Mask {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cells = Vec::new();
+        for (r, line) in s.lines().enumerate() {
+            for (c, l) in line.chars().enumerate() {
+                if l != '.' && !l.is_whitespace() {
+                    cells.push(((r as i32, c as i32), l));
+                }
+            }
+        }
+        if cells.is_empty() {
+            return Err("the mask has no letters".to_string());
+        }
+        let mut variants: Vec<Vec<(Pos, char)>> = Vec::new();
+        for reflect in [false, true] {
+            let mut v: Vec<(Pos, char)> = cells
+                .iter()
+                .map(|((r, c), l)| ((*r, if reflect { -c } else { *c }), *l))
+                .collect();
+            for _ in 0..4 {
+                let n = normalized(&v);
+                if !variants.contains(&n) {
+                    variants.push(n);
+                }
+                // a quarter turn clockwise
+                v = v.iter().map(|((r, c), l)| ((*c, -r), *l)).collect();
+            }
+        }
+        return Ok(Mask { variants });
+    }
+}
+
+// find_mask returns the top left corner and variant of every occurrence of the mask
+pub fn find_mask(grid: &Grid<char>, mask: &Mask) -> Vec<(Pos, usize)> {
+    let mut matches = Vec::new();
+    for (row, col) in grid.positions() {
+        for (i, v) in mask.variants.iter().enumerate() {
+            if v.iter()
+                .all(|((r, c), l)| grid.get((row + r, col + c)) == Some(l))
+            {
+                matches.push(((row, col), i));
+            }
+        }
+    }
+    return matches;
+}
+
+// matched marks the cells covered by the word and mask matches
+pub fn matched(
+    grid: &Grid<char>,
+    words: &Vec<String>,
+    matches: &Vec<Match>,
+    mask: &Mask,
+    mask_matches: &Vec<(Pos, usize)>,
+) -> Grid<bool> {
+    let mut covered = Grid::new(grid.width(), grid.height(), false);
+    for m in matches {
+        for p in m.cells(words[m.word].chars().count()) {
+            covered[p] = true;
+        }
+    }
+    for ((row, col), v) in mask_matches {
+        for ((r, c), _) in &mask.variants[*v] {
+            covered[(row + r, col + c)] = true;
+        }
+    }
+    return covered;
+}
+
+// render shows the grid with the covered cells in colour, or without colour
+// with `.` in place of the other cells like the puzzle text does
+pub fn render(grid: &Grid<char>, covered: &Grid<bool>, colour: bool) -> String {
+    let mut out = String::new();
+    for (p, c) in grid.iter() {
+        if p.1 == 0 && p.0 > 0 {
+            out.push('\n');
+        }
+        let shown = match (covered[p], colour) {
+            (true, true) => style(c).green().bold().to_string(),
+            (false, true) => style(c).dim().to_string(),
+            (true, false) => c.to_string(),
+            (false, false) => ".".to_string(),
+        };
+        out.push_str(&shown);
+    }
+    return out;
+}
+
+pub const XMAS: &str = "XMAS";
+
+pub const X_MAS: &str = "M.S\n.A.\nM.S";
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = Grid<char>;
+
+    fn parse(txt: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(Self::DAY, txt, "a letter", Some)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        find_words(input, &vec![XMAS.to_string()]).len().into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        find_mask(input, &X_MAS.parse().unwrap()).len().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+    use regex::Regex;
+
+    use super::*;
+    use crate::{differential::cross_check, generate, grid::AROUND};
+
+    // part1 and part2 are the first solutions with the puzzle words baked in
+    fn part1(input: &Grid<char>) -> usize {
+        let mut matches: usize = 0;
+        for (row, col) in input.positions() {
+            for (rd, cd) in AROUND {
+                let w: String = (0..4)
+                    .map_while(|i| input.get((row + i * rd, col + i * cd)))
+                    .collect();
+                if w == "XMAS" {
+                    matches += 1;
+                }
+            }
+        }
+        return matches;
+    }
+
+    fn part2(input: &Grid<char>) -> usize {
+        let mut matches: usize = 0;
+        let re = Regex::new(r"(M.S.A.M.S)|(S.S.A.M.M)|(M.M.A.S.S)|(S.M.A.S.M)").unwrap();
+        for (row, col) in input.positions() {
+            let mut w = String::new();
+            for rd in -1..2 {
+                for cd in -1..2 {
+                    if let Some(c) = input.get((row + rd, col + cd)) {
+                        w.push(*c);
+                    }
+                }
+            }
+            if w.len() == 9 && re.is_match(&w) {
+                matches += 1;
+            }
+        }
+        return matches;
+    }
+
+    // find_words_naive checks every word at every cell in every direction
+    fn find_words_naive(grid: &Grid<char>, words: &Vec<String>) -> Vec<Match> {
+        let mut matches = Vec::new();
+        for (word, w) in words.iter().enumerate() {
+            let letters: Vec<char> = w.chars().collect();
+            let dirs = match letters.len() {
+                0 => continue,
+                1 => &[(0, 1)][..],
+                _ => &AROUND[..],
+            };
+            for start in grid.positions() {
+                for &dir in dirs {
+                    let m = Match { word, start, dir };
+                    if m.cells(letters.len())
+                        .zip(&letters)
+                        .all(|(p, c)| grid.get(p) == Some(c))
+                    {
+                        matches.push(m);
+                    }
+                }
+            }
+        }
+        return matches;
+    }
+
+    #[test]
+    fn check() {
+        let grid = Day4::parse(include_str!("test_input.txt")).unwrap();
+        assert_eq!(Answer::Num(18), Day4::part1(&grid));
+        assert_eq!(Answer::Num(9), Day4::part2(&grid));
+        let words = vec!["MAS".to_string(), "XMAS".to_string(), String::new()];
+        let matches = find_words(&grid, &words);
+        assert_eq!(18, matches.iter().filter(|m| m.word == 1).count());
+        let m = Match {
+            word: 1,
+            start: (9, 3),
+            dir: (-1, -1),
+        };
+        assert!(matches.contains(&m));
+        assert_eq!(
+            vec![(9, 3), (8, 2), (7, 1), (6, 0)],
+            m.cells(4).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn check_mask() {
+        let x_mas: Mask = X_MAS.parse().unwrap();
+        assert_eq!(4, x_mas.variants.len());
+        let l: Mask = "AB\nC.".parse().unwrap();
+        assert_eq!(8, l.variants.len());
+        let grid = Day4::parse("XBA\n..C\n").unwrap();
+        assert_eq!(vec![((0, 1), 4)], find_mask(&grid, &l));
+        assert!(".\n".parse::<Mask>().is_err());
+    }
+
+    #[test]
+    fn check_render() {
+        let grid = Day4::parse("Q.X...\n.SAMX.\n.A..A.\nXMAS.S\n.X....\n").unwrap();
+        let words = vec![XMAS.to_string()];
+        let matches = find_words(&grid, &words);
+        let starts: Vec<(Pos, &str)> = matches
+            .iter()
+            .map(|m| (m.start, direction(m.dir)))
+            .collect();
+        assert_eq!(
+            vec![
+                ((0, 2), "down-right"),
+                ((1, 4), "left"),
+                ((3, 0), "right"),
+                ((4, 1), "up")
+            ],
+            starts
+        );
+        let mask = X_MAS.parse().unwrap();
+        let covered = matched(&grid, &words, &matches, &mask, &Vec::new());
+        assert_eq!(
+            "..X...\n.SAMX.\n.A..A.\nXMAS.S\n.X....",
+            render(&grid, &covered, false)
+        );
+    }
+
+    #[test]
+    fn check_automaton_vs_naive() {
+        let r = cross_check(
+            200,
+            20,
+            |rng| {
+                let grid =
+                    Day4::parse(&generate::generate(4, rng.gen_range(1..10), rng.gen()).unwrap())
+                        .unwrap();
+                let words: Vec<String> = (0..rng.gen_range(1..6))
+                    .map(|_| {
+                        (0..rng.gen_range(1..5))
+                            .map(|_| ['X', 'M', 'A', 'S'][rng.gen_range(0..4)])
+                            .collect()
+                    })
+                    .collect();
+                (grid, words)
+            },
+            |_| Vec::new(),
+            |(grid, words)| {
+                let mut matches = find_words_naive(grid, words);
+                matches.sort();
+                matches
+            },
+            |(grid, words)| find_words(grid, words),
+        );
+        r.unwrap_or_else(|m| panic!("{m}"));
+    }
+
+    #[test]
+    fn check_search_vs_baked_in() {
+        let r = cross_check(
+            50,
+            4,
+            |rng| Day4::parse(&generate::generate(4, 8, rng.gen()).unwrap()).unwrap(),
+            |_| Vec::new(),
+            |grid| (part1(grid), part2(grid)),
+            |grid| {
+                let words = find_words(grid, &vec![XMAS.to_string()]);
+                (words.len(), find_mask(grid, &X_MAS.parse().unwrap()).len())
+            },
+        );
+        r.unwrap_or_else(|m| panic!("{m}"));
+    }
+}
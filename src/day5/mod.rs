@@ -0,0 +1,616 @@
+use std::{collections::HashMap, fmt};
+
+use rayon::prelude::*;
+
+use crate::{
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
+// Disorder tells why the rules can't put the pages of a manual in one order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Disorder {
+    // the rules contradict each other, the pages are listed along the cycle
+    Cycle(Vec<i32>),
+    // the rules don't tell which of these pages comes first
+    Ambiguous(Vec<i32>),
+}
+
+impl fmt::Display for Disorder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pages = |pages: &Vec<i32>, sep| {
+            pages
+                .iter()
+                .map(|p| p.to_string())
+                .collect::<Vec<String>>()
+                .join(sep)
+        };
+        match self {
+            Disorder::Cycle(cycle) => {
+                write!(f, "rules go round in a cycle: {}", pages(cycle, " before "))
+            }
+            Disorder::Ambiguous(first) => {
+                write!(f, "rules don't order pages {}", pages(first, ", "))
+            }
+        }
+    }
+}
+
+// Rules are compiled into a precedence matrix: every page they mention gets a dense id,
+// and row id holds the bits of the pages that must come after it
+#[derive(Debug, Clone, Default)]
+pub struct Rules {
+    // the rules `before|after` in the order they were given
+    pub list: Vec<(i32, i32)>,
+    ids: HashMap<i32, usize>,
+    // u64 words per row of the matrix
+    words: usize,
+    after: Vec<u64>,
+}
+
+impl Rules {
+    pub fn new(list: Vec<(i32, i32)>) -> Rules {
+        let mut ids: HashMap<i32, usize> = HashMap::new();
+        for &(before, after) in &list {
+            for page in [before, after] {
+                let id = ids.len();
+                ids.entry(page).or_insert(id);
+            }
+        }
+        let words = ids.len().div_ceil(64);
+        let mut matrix = vec![0u64; ids.len() * words];
+        for &(before, after) in &list {
+            let (b, a) = (ids[&before], ids[&after]);
+            matrix[b * words + a / 64] |= 1 << (a % 64);
+        }
+        return Rules {
+            list,
+            ids,
+            words,
+            after: matrix,
+        };
+    }
+
+    // precedes tells whether a rule puts page a before page b
+    pub fn precedes(&self, a: i32, b: i32) -> bool {
+        let (Some(&a), Some(&b)) = (self.ids.get(&a), self.ids.get(&b)) else {
+            return false;
+        };
+        return self.after[a * self.words + b / 64] & (1 << (b % 64)) != 0;
+    }
+
+    // row is the bitset of the pages that must come after the page with this id
+    fn row(&self, id: usize) -> &[u64] {
+        &self.after[id * self.words..(id + 1) * self.words]
+    }
+}
+
+// fix_manual sorts the pages topologically along the rules between them,
+// it fails unless the rules give exactly one order
+pub fn fix_manual(manual: &Vec<i32>, rules: &Rules) -> Result<Vec<i32>, Disorder> {
+    let n = manual.len();
+    let mut after: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut before: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for i in 0..n {
+        for j in 0..n {
+            if i != j && rules.precedes(manual[i], manual[j]) {
+                after[i].push(j);
+                before[j].push(i);
+            }
+        }
+    }
+    let mut waiting: Vec<usize> = before.iter().map(|b| b.len()).collect();
+    let mut placed = vec![false; n];
+    let mut fixed = Vec::with_capacity(n);
+    while fixed.len() < n {
+        let ready: Vec<usize> = (0..n).filter(|&i| !placed[i] && waiting[i] == 0).collect();
+        match ready[..] {
+            [i] => {
+                placed[i] = true;
+                fixed.push(manual[i]);
+                for &j in &after[i] {
+                    waiting[j] -= 1;
+                }
+            }
+            [] => {
+                // every page left waits for another one left, walking back from any of them
+                // must come round to a page already seen
+                let mut walk: Vec<usize> = vec![(0..n).find(|&i| !placed[i]).unwrap()];
+                loop {
+                    let last = walk[walk.len() - 1];
+                    let prev = *before[last].iter().find(|&&j| !placed[j]).unwrap();
+                    if let Some(start) = walk.iter().position(|&j| j == prev) {
+                        let mut cycle: Vec<i32> =
+                            walk[start..].iter().rev().map(|&j| manual[j]).collect();
+                        cycle.push(cycle[0]);
+                        return Err(Disorder::Cycle(cycle));
+                    }
+                    walk.push(prev);
+                }
+            }
+            _ => {
+                return Err(Disorder::Ambiguous(
+                    ready.iter().map(|&i| manual[i]).collect(),
+                ))
+            }
+        }
+    }
+    return Ok(fixed);
+}
+
+// is_ok walks the manual once keeping the set of pages seen so far,
+// a page is out of order when one that must come after it has been seen
+pub fn is_ok(manual: &Vec<i32>, rules: &Rules) -> bool {
+    let mut seen = vec![0u64; rules.words];
+    for page in manual {
+        let Some(&id) = rules.ids.get(page) else {
+            continue;
+        };
+        if rules.row(id).iter().zip(&seen).any(|(a, s)| a & s != 0) {
+            return false;
+        }
+        seen[id / 64] |= 1 << (id % 64);
+    }
+    return true;
+}
+
+// validate checks all the manuals in parallel
+pub fn validate(manuals: &Vec<Vec<i32>>, rules: &Rules) -> Vec<bool> {
+    manuals.par_iter().map(|m| is_ok(m, rules)).collect()
+}
+
+// Violation is a broken rule `before|after`: after is at position `at.1`, ahead of before at `at.0`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    pub before: i32,
+    pub after: i32,
+    pub at: (usize, usize),
+}
+
+// violations lists every rule broken by the manual
+pub fn violations(manual: &Vec<i32>, rules: &Rules) -> Vec<Violation> {
+    let mut broken = Vec::new();
+    for i in 0..manual.len() {
+        for j in 0..i {
+            if rules.precedes(manual[i], manual[j]) {
+                broken.push(Violation {
+                    before: manual[i],
+                    after: manual[j],
+                    at: (i, j),
+                });
+            }
+        }
+    }
+    return broken;
+}
+
+// Move takes a page from its position in the original manual to its position in the fixed one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub page: i32,
+    pub from: usize,
+    pub to: usize,
+}
+
+// moves are the fewest pages to move to turn the manual into the fixed one: the pages of the
+// longest run keeping their relative order stay, found by patience sorting of their new positions
+pub fn moves(manual: &Vec<i32>, fixed: &Vec<i32>) -> Vec<Move> {
+    let to: Vec<usize> = manual
+        .iter()
+        .map(|p| fixed.iter().position(|f| f == p).unwrap())
+        .collect();
+    // tops[k] is the index of the smallest end of an increasing run of length k + 1
+    let mut tops: Vec<usize> = Vec::new();
+    let mut prev: Vec<Option<usize>> = vec![None; to.len()];
+    for i in 0..to.len() {
+        let k = tops.partition_point(|&t| to[t] < to[i]);
+        prev[i] = if k > 0 { Some(tops[k - 1]) } else { None };
+        if k == tops.len() {
+            tops.push(i);
+        } else {
+            tops[k] = i;
+        }
+    }
+    let mut stays = vec![false; to.len()];
+    let mut i = tops.last().copied();
+    while let Some(j) = i {
+        stays[j] = true;
+        i = prev[j];
+    }
+    let mut moved: Vec<Move> = (0..to.len())
+        .filter(|&i| !stays[i])
+        .map(|i| Move {
+            page: manual[i],
+            from: i,
+            to: to[i],
+        })
+        .collect();
+    // putting each page right after the page preceding it in the fixed manual works
+    // when done from the front
+    moved.sort_by_key(|m| m.to);
+    return moved;
+}
+
+// part2 adds up the middle pages of the fixed manuals
+fn part2(rules: &Rules, manuals: &Vec<Vec<i32>>) -> Result<i32, Disorder> {
+    let mut sum = 0i32;
+    for (manual, ok) in manuals.iter().zip(validate(manuals, rules)) {
+        if !ok {
+            let fixed = fix_manual(manual, rules)?;
+            sum += fixed[fixed.len() / 2];
+        }
+    }
+    return Ok(sum);
+}
+
+fn part1(rules: &Rules, manuals: &Vec<Vec<i32>>) -> i32 {
+    let mut sum = 0i32;
+    for (manual, ok) in manuals.iter().zip(validate(manuals, rules)) {
+        if ok {
+            sum += manual[manual.len() / 2];
+        }
+    }
+    return sum;
+}
+
+// load_input reads the rules, an empty line, and the updates; rules are kept in the order given,
+// and a rule putting a page before itself or repeating an earlier one is an error
+fn load_input(txt: &str) -> Result<(Rules, Vec<Vec<i32>>), ParseError> {
+    let sections = parse::sections(Day5::DAY, txt);
+    if sections.len() < 2 {
+        return Err(parse::missing(
+            Day5::DAY,
+            txt,
+            "an empty line followed by updates",
+        ));
+    }
+    // another empty line can only be where an update should be
+    if let Some(line) = sections[2..].iter().flatten().next() {
+        return Err(ParseError::new(Day5::DAY, line.no - 1, 1, "an update", ""));
+    }
+    let mut rules: Vec<(i32, i32)> = Vec::new();
+    let mut given: HashMap<(i32, i32), usize> = HashMap::new();
+    for line in &sections[0] {
+        let Some((before, after)) = line.text.split_once('|') else {
+            return Err(line.error(line.text, "a rule `X|Y`"));
+        };
+        let rule = (line.number(before)?, line.number(after)?);
+        if rule.0 == rule.1 {
+            return Err(line.error(after, &format!("a page other than {}", rule.0)));
+        }
+        if let Some(no) = given.insert(rule, line.no) {
+            return Err(line.error(line.text, &format!("a rule not already on line {no}")));
+        }
+        rules.push(rule);
+    }
+    let mut manuals: Vec<Vec<i32>> = Vec::new();
+    for line in &sections[1] {
+        manuals.push(
+            line.text
+                .split(',')
+                .map(|x| line.number(x))
+                .collect::<Result<Vec<i32>, _>>()?,
+        );
+    }
+    return Ok((Rules::new(rules), manuals));
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = (Rules, Vec<Vec<i32>>);
+
+    fn parse(txt: &str) -> Result<Self::Input, ParseError> {
+        load_input(txt)
+    }
+
+    fn part1((rules, manuals): &Self::Input) -> Answer {
+        part1(rules, manuals).into()
+    }
+
+    fn part2((rules, manuals): &Self::Input) -> Answer {
+        match part2(rules, manuals) {
+            Ok(sum) => sum.into(),
+            Err(_) => Answer::Unsolved,
+        }
+    }
+
+    fn diagnostics((rules, manuals): &Self::Input, part: u8) -> Vec<(&'static str, String)> {
+        if part == 1 {
+            return Vec::new();
+        }
+        for (i, manual) in manuals.iter().enumerate() {
+            if is_ok(manual, rules) {
+                continue;
+            }
+            if let Err(e) = fix_manual(manual, rules) {
+                return vec![("update", (i + 1).to_string()), ("disorder", e.to_string())];
+            }
+        }
+        return Vec::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use rand::{seq::SliceRandom, thread_rng, Rng, SeedableRng};
+
+    use super::*;
+    use crate::differential::{cross_check, shrink_vec};
+
+    // fix_manual2 and swap_manual are earlier fixes, sorting by a partial comparator
+    // and swapping the first misplaced pair over and over
+    fn fix_manual2(mut manual: Vec<i32>, rules: &Rules) -> Vec<i32> {
+        manual.sort_by(|&a, &b| {
+            if rules.precedes(a, b) {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        });
+        return manual;
+    }
+
+    fn swap_manual(mut manual: Vec<i32>, rules: &Rules) -> Vec<i32> {
+        for i in (0..manual.len()).rev() {
+            for j in 0..i {
+                if rules.precedes(manual[i], manual[j]) {
+                    manual.swap(i, j);
+                    return swap_manual(manual, rules);
+                }
+            }
+        }
+        return manual;
+    }
+
+    // part2_3 shuffles every manual until it happens to be in order
+    fn part2_3(rules: &Rules, manuals: &Vec<Vec<i32>>) -> i32 {
+        // I didn't have patience to let it actually solve the task. But it should work, right?
+        let mut sum = 0i32;
+        for manual in manuals {
+            if !is_ok(manual, rules) {
+                let mut fixed = manual.clone();
+                while !is_ok(&fixed, rules) {
+                    fixed.shuffle(&mut thread_rng());
+                }
+                sum += fixed[fixed.len() / 2];
+            }
+        }
+        return sum;
+    }
+
+    #[test]
+    fn check_rule() {
+        let (rules, manuals) = load_input(include_str!("test_input.txt")).unwrap();
+        assert!(is_ok(&manuals[0], &rules));
+        assert!(is_ok(&manuals[1], &rules));
+        assert!(is_ok(&manuals[2], &rules));
+        assert!(!is_ok(&manuals[3], &rules));
+        assert!(!is_ok(&manuals[4], &rules));
+        assert!(!is_ok(&manuals[5], &rules));
+    }
+
+    #[test]
+    fn check_parser() {
+        let (rules, manuals) = load_input("3|1\n2|3\n\n1,2\n7\n").unwrap();
+        assert_eq!(vec![(3, 1), (2, 3)], rules.list);
+        assert_eq!(vec![vec![1, 2], vec![7]], manuals);
+        assert_eq!(9, part1(&rules, &manuals));
+        let errors = [
+            ("1|2\n1,2\n", (3, 1)),
+            ("1|2\n\n1,2\n\n2,1\n", (4, 1)),
+            ("1|2\n1-3\n\n1,2\n", (2, 1)),
+            ("1|2\n4|4\n\n1,2\n", (2, 3)),
+            ("1|2\n3|1\n1|2\n\n1,2\n", (3, 1)),
+            ("1|2\n\n1,,2\n", (3, 3)),
+        ];
+        for (txt, at) in errors {
+            let e = load_input(txt).unwrap_err();
+            assert_eq!(at, (e.line, e.column), "{txt:?}: {e}");
+        }
+        let e = load_input("1|2\n3|1\n1|2\n\n1,2\n").unwrap_err();
+        assert_eq!("a rule not already on line 1", e.expected);
+    }
+
+    // is_ok_pairwise is the earlier check, looking up the rules of every pair of pages
+    fn is_ok_pairwise(manual: &Vec<i32>, rules: &Rules) -> bool {
+        for i in (0..manual.len()).rev() {
+            for j in 0..i {
+                if rules.list.contains(&(manual[i], manual[j])) {
+                    return false;
+                }
+            }
+        }
+        return true;
+    }
+
+    #[test]
+    fn check_rule_index_vs_pairwise() {
+        // a few hundred random rules, some contradicting each other, over more than 64 pages
+        let mut rng = rand::rngs::StdRng::seed_from_u64(23);
+        let list: Vec<(i32, i32)> = (0..600)
+            .map(|_| (rng.gen_range(10..100), rng.gen_range(10..100)))
+            .collect();
+        let rules = Rules::new(list);
+        let r = cross_check(
+            1000,
+            23,
+            |rng| {
+                let mut manual = random_manual(rng);
+                // pages no rule mentions are fine anywhere
+                manual.push(rng.gen_range(100..110));
+                manual
+            },
+            shrink_vec,
+            |manual| is_ok_pairwise(manual, &rules),
+            |manual| is_ok(manual, &rules),
+        );
+        r.unwrap_or_else(|m| panic!("{m}"));
+        let (rules, manuals) = load_input(include_str!("test_input.txt")).unwrap();
+        assert_eq!(
+            vec![true, true, true, false, false, false],
+            validate(&manuals, &rules)
+        );
+        assert!(rules.precedes(47, 53));
+        assert!(!rules.precedes(53, 47));
+        assert!(!rules.precedes(47, 1));
+    }
+
+    // rules order every pair of pages, like the puzzle does for pages of an update
+    fn ordered(order: &Vec<i32>) -> Rules {
+        let mut rules = Vec::new();
+        for i in 0..order.len() {
+            for j in i + 1..order.len() {
+                rules.push((order[i], order[j]));
+            }
+        }
+        return Rules::new(rules);
+    }
+
+    fn random_manual(rng: &mut rand::rngs::StdRng) -> Vec<i32> {
+        let mut manual: Vec<i32> = (10..100).collect();
+        manual.shuffle(rng);
+        manual.truncate(rng.gen_range(1..8));
+        return manual;
+    }
+
+    // the page order is the sorted one, so that shrinking a manual keeps it valid
+    fn sorted(manual: &Vec<i32>) -> Rules {
+        let mut order = manual.clone();
+        order.sort();
+        return ordered(&order);
+    }
+
+    #[test]
+    fn check_fix_vs_sort() {
+        let r = cross_check(
+            500,
+            5,
+            random_manual,
+            shrink_vec,
+            |manual| fix_manual(manual, &sorted(manual)).unwrap(),
+            |manual| fix_manual2(manual.clone(), &sorted(manual)),
+        );
+        r.unwrap_or_else(|m| panic!("{m}"));
+    }
+
+    #[test]
+    fn check_fix_vs_swap() {
+        let r = cross_check(
+            500,
+            5,
+            random_manual,
+            shrink_vec,
+            |manual| fix_manual(manual, &sorted(manual)).unwrap(),
+            |manual| swap_manual(manual.clone(), &sorted(manual)),
+        );
+        r.unwrap_or_else(|m| panic!("{m}"));
+    }
+
+    #[test]
+    fn check_disorder() {
+        let (rules, manuals) = load_input(include_str!("test_input.txt")).unwrap();
+        assert_eq!(
+            Ok(vec![97, 75, 47, 61, 53]),
+            fix_manual(&manuals[3], &rules)
+        );
+        assert_eq!(Answer::Num(123), Day5::part2(&(rules, manuals)));
+        let rules = Rules::new(vec![(1, 2), (2, 3), (3, 1), (4, 1)]);
+        assert_eq!(
+            Err(Disorder::Cycle(vec![2, 3, 1, 2])),
+            fix_manual(&vec![4, 1, 2, 3], &rules)
+        );
+        let manuals = vec![vec![1, 4], vec![5, 1, 4, 3]];
+        assert_eq!(
+            Err(Disorder::Ambiguous(vec![5, 4, 3])),
+            fix_manual(&manuals[1], &rules)
+        );
+        let input = (rules, manuals);
+        assert_eq!(Answer::Unsolved, Day5::part2(&input));
+        assert_eq!(
+            vec![
+                ("update", "2".to_string()),
+                ("disorder", "rules don't order pages 5, 4, 3".to_string())
+            ],
+            Day5::diagnostics(&input, 2)
+        );
+    }
+
+    #[test]
+    fn check_report() {
+        let (rules, manuals) = load_input(include_str!("test_input.txt")).unwrap();
+        let broken = violations(&manuals[5], &rules);
+        let pairs: Vec<(i32, i32, (usize, usize))> =
+            broken.iter().map(|v| (v.before, v.after, v.at)).collect();
+        assert_eq!(
+            vec![
+                (75, 13, (2, 1)),
+                (29, 13, (3, 1)),
+                (47, 13, (4, 1)),
+                (47, 29, (4, 3))
+            ],
+            pairs
+        );
+        assert!(violations(&manuals[0], &rules).is_empty());
+        let fixed = fix_manual(&manuals[5], &rules).unwrap();
+        assert_eq!(vec![97, 75, 47, 29, 13], fixed);
+        let m = moves(&manuals[5], &fixed);
+        assert_eq!(2, m.len());
+        let m = moves(&vec![1, 2, 3, 4, 5], &vec![2, 3, 4, 5, 1]);
+        assert_eq!(
+            vec![Move {
+                page: 1,
+                from: 0,
+                to: 4
+            }],
+            m
+        );
+    }
+
+    // kept is the length of the longest common subsequence of the manual and its fixed order
+    fn kept(manual: &Vec<i32>, fixed: &Vec<i32>) -> usize {
+        let mut lcs = vec![vec![0; fixed.len() + 1]; manual.len() + 1];
+        for i in 0..manual.len() {
+            for j in 0..fixed.len() {
+                lcs[i + 1][j + 1] = if manual[i] == fixed[j] {
+                    lcs[i][j] + 1
+                } else {
+                    lcs[i][j + 1].max(lcs[i + 1][j])
+                };
+            }
+        }
+        return lcs[manual.len()][fixed.len()];
+    }
+
+    #[test]
+    fn check_moves_vs_lcs() {
+        let r = cross_check(
+            300,
+            22,
+            random_manual,
+            shrink_vec,
+            |manual| {
+                let fixed = fix_manual(manual, &sorted(manual)).unwrap();
+                manual.len() - kept(manual, &fixed)
+            },
+            |manual| moves(manual, &fix_manual(manual, &sorted(manual)).unwrap()).len(),
+        );
+        r.unwrap_or_else(|m| panic!("{m}"));
+    }
+
+    #[test]
+    fn check_fix_vs_bogosort() {
+        let r = cross_check(
+            100,
+            5,
+            |rng| vec![random_manual(rng), random_manual(rng)],
+            shrink_vec,
+            |manuals| part2(&sorted(&manuals.concat()), manuals).unwrap(),
+            |manuals| part2_3(&sorted(&manuals.concat()), manuals),
+        );
+        r.unwrap_or_else(|m| panic!("{m}"));
+    }
+}
@@ -0,0 +1,275 @@
+use rayon::prelude::*;
+
+use crate::{
+    grid::{Dir, Grid, Pos},
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
+// Lab is the map of obstacles along with a jump table for every direction:
+// the cell where the guard walking that way stops in front of an obstacle, None when leaving the map
+pub struct Lab {
+    pub obstacles: Grid<bool>,
+    jumps: Vec<Grid<Option<Pos>>>,
+}
+
+// Step is the first time the guard enters a cell, coming from the cell before facing dir
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub pos: Pos,
+    pub from: Pos,
+    pub dir: Dir,
+}
+
+// Visits remembers the stops of one walk after another without clearing between them:
+// a stop only counts as seen when it is marked with the number of the current walk
+pub struct Visits {
+    seen: Grid<[u32; 4]>,
+    walk: u32,
+}
+
+impl Visits {
+    pub fn new(lab: &Lab) -> Visits {
+        let seen = Grid::new(lab.obstacles.width(), lab.obstacles.height(), [0; 4]);
+        return Visits { seen, walk: 0 };
+    }
+}
+
+impl Lab {
+    pub fn new(obstacles: Grid<bool>) -> Lab {
+        let mut jumps = Vec::new();
+        for d in Dir::ALL {
+            let mut jump: Grid<Option<Pos>> =
+                Grid::new(obstacles.width(), obstacles.height(), None);
+            // the cell next to p in direction d is always done before p
+            let mut order: Vec<Pos> = obstacles.positions().collect();
+            if d == Dir::Down || d == Dir::Right {
+                order.reverse();
+            }
+            for p in order {
+                jump[p] = match obstacles.neighbour(p, d) {
+                    None => None,
+                    Some(n) if obstacles[n] => Some(p),
+                    Some(n) => jump[n],
+                };
+            }
+            jumps.push(jump);
+        }
+        return Lab { obstacles, jumps };
+    }
+
+    // jump finds where the guard stops walking from pos in direction dir,
+    // the table is patched for the extra obstacle when it is on the way
+    fn jump(&self, pos: Pos, dir: Dir, extra: Pos) -> Option<Pos> {
+        let stop = self.jumps[dir as usize][pos];
+        let d = dir.delta();
+        let (dr, dc) = (extra.0 - pos.0, extra.1 - pos.1);
+        // extra is ahead when it is on the line of the walk, at least one step away
+        let ahead = dr * d.0 + dc * d.1;
+        if ahead < 1 || dr * d.1 != dc * d.0 {
+            return stop;
+        }
+        if let Some(s) = stop {
+            if (s.0 - pos.0) * d.0 + (s.1 - pos.1) * d.1 < ahead {
+                return stop;
+            }
+        }
+        return Some((extra.0 - d.0, extra.1 - d.1));
+    }
+
+    // loops tells whether the guard starting at pos facing dir walks in circles once extra
+    // is blocked, it does when it stops at the same cell facing the same way again
+    pub fn loops(&self, mut pos: Pos, mut dir: Dir, extra: Pos, visits: &mut Visits) -> bool {
+        visits.walk = visits.walk.wrapping_add(1);
+        if visits.walk == 0 {
+            // the numbers wrapped around, old marks could pass for this walk's
+            visits.seen = visits.seen.map(|_| [0; 4]);
+            visits.walk = 1;
+        }
+        loop {
+            let Some(stop) = self.jump(pos, dir, extra) else {
+                return false;
+            };
+            let mark = &mut visits.seen[stop][dir as usize];
+            if *mark == visits.walk {
+                return true;
+            }
+            *mark = visits.walk;
+            pos = stop;
+            dir = dir.turn_right();
+        }
+    }
+
+    // route walks the guard from pos until it leaves the map, listing every cell the first time
+    // it is entered; it stops early should the guard come back to a cell facing the same way
+    pub fn route(&self, mut pos: Pos) -> Vec<Step> {
+        let mut dir = Dir::Up;
+        let mut facing: Grid<u8> = self.obstacles.map(|_| 0);
+        facing[pos] = 1 << dir as u8;
+        let mut route = vec![Step {
+            pos,
+            from: pos,
+            dir,
+        }];
+        while let Some(next) = self.obstacles.neighbour(pos, dir) {
+            if self.obstacles[next] {
+                dir = dir.turn_right();
+            } else {
+                if facing[next] == 0 {
+                    route.push(Step {
+                        pos: next,
+                        from: pos,
+                        dir,
+                    });
+                }
+                pos = next;
+            }
+            let bit = 1 << dir as u8;
+            if facing[pos] & bit != 0 {
+                break;
+            }
+            facing[pos] |= bit;
+        }
+        return route;
+    }
+}
+
+fn part1(lab: &Lab, pos: Pos) -> usize {
+    return lab.route(pos).len();
+}
+
+// part2 only tries obstacles on the route, the guard wouldn't notice one anywhere else.
+// Up to the cell of the obstacle the route stays the same, so the walk starts right in front of it.
+fn part2(lab: &Lab, pos: Pos) -> usize {
+    let route = lab.route(pos);
+    return route[1..]
+        .par_iter()
+        .map_init(
+            || Visits::new(lab),
+            |visits, step| lab.loops(step.from, step.dir, step.pos, visits),
+        )
+        .filter(|&loops| loops)
+        .count();
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Input = (Lab, Pos);
+
+    fn parse(txt: &str) -> Result<Self::Input, ParseError> {
+        let map = Grid::parse(Self::DAY, txt, "`.`, `#` or `^`", |c| match c {
+            '.' | '#' | '^' => Some(c),
+            _ => None,
+        })?;
+        let guard_pos = map
+            .find(|c| *c == '^')
+            .ok_or_else(|| parse::missing(Self::DAY, txt, "a guard `^`"))?;
+        return Ok((Lab::new(map.map(|c| *c == '#')), guard_pos));
+    }
+
+    fn part1((lab, guard_pos): &Self::Input) -> Answer {
+        part1(lab, *guard_pos).into()
+    }
+
+    fn part2((lab, guard_pos): &Self::Input) -> Answer {
+        part2(lab, *guard_pos).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use rand::Rng;
+
+    use super::*;
+    use crate::differential::{cross_check, shrink_vec};
+
+    // walks_in_circles moves the guard a cell at a time remembering every (cell, direction)
+    fn walks_in_circles(obstacles: &Grid<bool>, mut pos: Pos) -> bool {
+        let mut dir = Dir::Up;
+        let mut seen = HashSet::new();
+        while seen.insert((pos, dir)) {
+            match obstacles.neighbour(pos, dir) {
+                None => return false,
+                Some(next) if obstacles[next] => dir = dir.turn_right(),
+                Some(next) => pos = next,
+            }
+        }
+        return true;
+    }
+
+    // part2_naive blocks every free cell in turn and walks the whole way
+    fn part2_naive(obstacles: &Grid<bool>, pos: Pos) -> usize {
+        let mut obstacles = obstacles.clone();
+        let mut count = 0;
+        for p in obstacles.positions() {
+            if obstacles[p] || p == pos {
+                continue;
+            }
+            obstacles[p] = true;
+            if walks_in_circles(&obstacles, pos) {
+                count += 1;
+            }
+            obstacles[p] = false;
+        }
+        return count;
+    }
+
+    #[test]
+    fn check() {
+        let input = Day6::parse(include_str!("test_input.txt")).unwrap();
+        assert_eq!(Answer::Num(41), Day6::part1(&input));
+        assert_eq!(Answer::Num(6), Day6::part2(&input));
+        let (lab, pos) = input;
+        assert_eq!(Some((1, 4)), lab.jump(pos, Dir::Up, (9, 4)));
+        assert_eq!(Some((4, 4)), lab.jump(pos, Dir::Up, (3, 4)));
+        assert_eq!(Some((7, 7)), lab.jump((7, 0), Dir::Right, (3, 4)));
+        assert_eq!(None, lab.jump(pos, Dir::Down, (6, 5)));
+        let mut visits = Visits::new(&lab);
+        assert!(lab.loops((6, 4), Dir::Left, (6, 3), &mut visits));
+        assert!(!lab.loops(pos, Dir::Up, (0, 0), &mut visits));
+        visits.walk = u32::MAX;
+        assert!(lab.loops((6, 4), Dir::Left, (6, 3), &mut visits));
+    }
+
+    #[test]
+    fn check_jumps_vs_walking() {
+        let guard = (5, 3);
+        // obstacles on a small map, maps where the guard walks in circles already are skipped
+        let r = cross_check(
+            300,
+            6,
+            |rng| {
+                (0..rng.gen_range(0..20))
+                    .map(|_| (rng.gen_range(0..8), rng.gen_range(0..10)))
+                    .filter(|&p| p != guard)
+                    .collect::<Vec<Pos>>()
+            },
+            shrink_vec,
+            |blocked| {
+                let mut obstacles = Grid::new(10, 8, false);
+                for &p in blocked {
+                    obstacles[p] = true;
+                }
+                if walks_in_circles(&obstacles, guard) {
+                    return None;
+                }
+                Some(part2_naive(&obstacles, guard))
+            },
+            |blocked| {
+                let mut obstacles = Grid::new(10, 8, false);
+                for &p in blocked {
+                    obstacles[p] = true;
+                }
+                if walks_in_circles(&obstacles, guard) {
+                    return None;
+                }
+                Some(part2(&Lab::new(obstacles), guard))
+            },
+        );
+        r.unwrap_or_else(|m| panic!("{m}"));
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
+type Op = fn(u64, u64) -> Option<u64>;
+
+// concat writes the digits of b after those of a, or None when that overflows
+fn concat(a: u64, b: u64) -> Option<u64> {
+    let digits = b.checked_ilog10().unwrap_or(0) + 1;
+    return a.checked_mul(10u64.pow(digits))?.checked_add(b);
+}
+
+// check drops every branch whose running value overflows
+fn check(test: &u64, lval: &u64, rvals: &[u64], ops: &[Op]) -> bool {
+    match rvals {
+        [] => *test == *lval,
+        [head, tail @ ..] => {
+            for op in ops {
+                if let Some(v) = (*op)(*lval, *head) {
+                    if check(test, &v, tail, ops) {
+                        return true;
+                    }
+                }
+            }
+            return false;
+        }
+    }
+}
+
+fn part1(input: &Vec<(u64, Vec<u64>)>) -> u64 {
+    let ops: [Op; 2] = [u64::checked_add, u64::checked_mul];
+    input
+        .iter()
+        .filter(|(result, operands)| check(result, &operands[0], &operands[1..], &ops))
+        .fold(0, |acc, (result, _)| acc + result)
+}
+
+fn part2(input: &Vec<(u64, Vec<u64>)>) -> u64 {
+    let ops: [Op; 3] = [u64::checked_add, u64::checked_mul, concat];
+    input
+        .iter()
+        .filter(|(result, operands)| check(result, &operands[0], &operands[1..], &ops))
+        .fold(0, |acc, (result, _)| acc + result)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input = Vec<(u64, Vec<u64>)>;
+
+    fn parse(txt: &str) -> Result<Self::Input, ParseError> {
+        let mut input: Vec<(u64, Vec<u64>)> = Vec::new();
+        for line in parse::lines(Self::DAY, txt) {
+            match line.text.split(": ").collect::<Vec<&str>>()[..] {
+                [result, operands] => input.push((
+                    line.number(result)?,
+                    operands
+                        .split(' ')
+                        .map(|x| line.number(x))
+                        .collect::<Result<_, _>>()?,
+                )),
+                _ => return Err(line.error(line.text, "`result: operands`")),
+            }
+        }
+        return Ok(input);
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check() {
+        let input = Day7::parse(include_str!("test_input.txt")).unwrap();
+        assert_eq!(3749, part1(&input));
+        assert_eq!(11387, part2(&input));
+    }
+
+    #[test]
+    fn check_overflow() {
+        let input = Day7::parse("1: 9999999999 9999999999\n19: 1 9").unwrap();
+        assert_eq!(19, part2(&input));
+    }
+
+    #[test]
+    fn check_negative() {
+        let e = Day7::parse("1: 5 -3").unwrap_err();
+        assert_eq!(((1, 6), "-3"), ((e.line, e.column), e.found.as_str()));
+    }
+}
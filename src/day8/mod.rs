@@ -0,0 +1,93 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    grid::{Grid, Pos},
+    parse::ParseError,
+    Answer, Solution,
+};
+
+fn part2(anntenae: &HashMap<char, Vec<Pos>>, map: &Grid<char>) -> usize {
+    let mut antinodes: HashSet<Pos> = HashSet::new();
+    for (_, annt) in anntenae.iter() {
+        for i in 0..annt.len() {
+            antinodes.insert(annt[i]);
+            for j in i + 1..annt.len() {
+                let r = (annt[i].0 - annt[j].0, annt[i].1 - annt[j].1);
+                for hop in 1.. {
+                    let node1 = (annt[i].0 + hop * r.0, annt[i].1 + hop * r.1);
+                    let node2 = (annt[j].0 - hop * r.0, annt[j].1 - hop * r.1);
+                    if map.contains(node1) {
+                        antinodes.insert(node1);
+                    }
+                    if map.contains(node2) {
+                        antinodes.insert(node2);
+                    }
+                    if !map.contains(node1) && !map.contains(node2) {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    return antinodes.len();
+}
+
+fn part1(anntenae: &HashMap<char, Vec<Pos>>, map: &Grid<char>) -> usize {
+    let mut antinodes: HashSet<Pos> = HashSet::new();
+    for (_, annt) in anntenae.iter() {
+        for i in 0..annt.len() {
+            for j in i + 1..annt.len() {
+                let r = (annt[i].0 - annt[j].0, annt[i].1 - annt[j].1);
+                let node1 = (annt[i].0 + r.0, annt[i].1 + r.1);
+                let node2 = (annt[j].0 - r.0, annt[j].1 - r.1);
+                if map.contains(node1) {
+                    antinodes.insert(node1);
+                }
+                if map.contains(node2) {
+                    antinodes.insert(node2);
+                }
+            }
+        }
+    }
+    return antinodes.len();
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    // antennae by frequency along with the map they are on
+    type Input = (HashMap<char, Vec<Pos>>, Grid<char>);
+
+    fn parse(txt: &str) -> Result<Self::Input, ParseError> {
+        let map = Grid::parse(Self::DAY, txt, "a map cell", Some)?;
+        let mut anntenae: HashMap<char, Vec<Pos>> = HashMap::new();
+        for (p, c) in map.iter() {
+            if *c != '.' {
+                anntenae.entry(*c).or_default().push(p);
+            }
+        }
+        return Ok((anntenae, map));
+    }
+
+    fn part1((anntenae, map): &Self::Input) -> Answer {
+        part1(anntenae, map).into()
+    }
+
+    fn part2((anntenae, map): &Self::Input) -> Answer {
+        part2(anntenae, map).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check() {
+        let (anntenae, map) = Day8::parse(include_str!("test_input.txt")).unwrap();
+        assert_eq!((12, 12), (map.height(), map.width()));
+        assert_eq!(14, part1(&anntenae, &map));
+        assert_eq!(34, part2(&anntenae, &map));
+    }
+}
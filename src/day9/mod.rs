@@ -1,7 +1,12 @@
-use std::{fs, iter::repeat};
+use std::iter::repeat_n;
+
+use crate::{
+    parse::{self, ParseError},
+    Answer, Solution,
+};
 
 #[derive(PartialEq, Clone, Debug)]
-struct File {
+pub struct File {
     pos: usize,
     id: i32,
     size: usize,
@@ -36,19 +41,16 @@ fn defragment(files: &mut Vec<File>, voids: &mut Vec<File>) {
 
 fn compact(disk: &mut Vec<Option<i32>>) {
     let mut next_free: usize = 0;
-    'next_block: for i in (0..disk.len()).rev() {
-        if disk[i] == None {
+    for i in (0..disk.len()).rev() {
+        if disk[i].is_none() {
             continue;
         }
         if next_free >= i {
             return;
         }
-        for j in next_free..disk.len() {
-            if disk[j] == None {
-                disk.swap(i, j);
-                next_free = j + 1;
-                continue 'next_block;
-            }
+        if let Some(j) = (next_free..disk.len()).find(|j| disk[*j].is_none()) {
+            disk.swap(i, j);
+            next_free = j + 1;
         }
     }
 }
@@ -65,49 +67,63 @@ fn file_checksum(files: &Vec<File>) -> i64 {
     })
 }
 
-fn load_input(file: &str) -> (Vec<Option<i32>>, Vec<File>, Vec<File>) {
-    let input = fs::read_to_string(file).unwrap().replace("\n", "");
+type Disk = Vec<Option<i32>>;
+
+fn load_input(txt: &str) -> Result<(Disk, Vec<File>, Vec<File>), ParseError> {
+    let mut digits: Vec<usize> = Vec::new();
+    for line in parse::lines(Day9::DAY, txt) {
+        for (col, c) in line.text.char_indices() {
+            match c.to_digit(10) {
+                None => return Err(line.error(&line.text[col..col + c.len_utf8()], "a digit")),
+                Some(d) => digits.push(d as usize),
+            }
+        }
+    }
     let mut disk: Vec<Option<i32>> = Vec::new();
     let mut id: i32 = 0;
-    let mut chars = input.chars();
     let mut files: Vec<File> = Vec::new();
     let mut voids: Vec<File> = Vec::new();
     let mut pos: usize = 0;
-    loop {
-        if let (Some(sz), fr) = (chars.next(), chars.next()) {
-            let size = sz as usize - '0' as usize;
-            disk.append(&mut repeat(Some(id)).take(size).collect());
-            files.push(File {
-                pos: pos,
-                size: size,
-                id: id,
+    for chunk in digits.chunks(2) {
+        let size = chunk[0];
+        disk.append(&mut repeat_n(Some(id), size).collect());
+        files.push(File { pos, size, id });
+        pos += size;
+        if let Some(&free) = chunk.get(1) {
+            disk.append(&mut repeat_n(None, free).collect());
+            voids.push(File {
+                pos,
+                size: free,
+                id: -1,
             });
-            pos += size;
-            if let Some(fr) = fr {
-                let free = fr as usize - '0' as usize;
-                disk.append(&mut repeat(None).take(free).collect());
-                voids.push(File {
-                    pos: pos,
-                    size: free,
-                    id: -1,
-                });
-                pos += free;
-            }
-        } else {
-            break;
+            pos += free;
         }
         id += 1;
     }
-    return (disk, files, voids);
+    return Ok((disk, files, voids));
 }
 
-fn main() {
-    let (disk, mut files, mut voids) = load_input("src/day9/input.txt");
-    let mut disk1 = disk.clone();
-    compact(&mut disk1);
-    println!("part1: {}", checksum(&disk1));
-    defragment(&mut files, &mut voids);
-    println!("part2: {}", file_checksum(&files));
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input = (Vec<Option<i32>>, Vec<File>, Vec<File>);
+
+    fn parse(txt: &str) -> Result<Self::Input, ParseError> {
+        load_input(txt)
+    }
+
+    fn part1((disk, _, _): &Self::Input) -> Answer {
+        let mut disk = disk.clone();
+        compact(&mut disk);
+        checksum(&disk).into()
+    }
+
+    fn part2((_, files, voids): &Self::Input) -> Answer {
+        let (mut files, mut voids) = (files.clone(), voids.clone());
+        defragment(&mut files, &mut voids);
+        file_checksum(&files).into()
+    }
 }
 
 #[cfg(test)]
@@ -121,7 +137,7 @@ mod tests {
                 Some(id) => print!("{id}"),
             }
         }
-        println!("");
+        println!();
     }
     fn dump_map(files: &Vec<File>, voids: &Vec<File>) {
         let mut disk: Vec<Option<i32>> = vec![
@@ -146,7 +162,7 @@ mod tests {
     }
     #[test]
     fn check1() {
-        let (mut disk, _, _) = load_input("src/day9/test_input.txt");
+        let (mut disk, _, _) = load_input(include_str!("test_input.txt")).unwrap();
         dump(&disk);
         compact(&mut disk);
         dump(&disk);
@@ -154,7 +170,7 @@ mod tests {
     }
     #[test]
     fn check2() {
-        let (_, mut files, mut voids) = load_input("src/day9/test_input.txt");
+        let (_, mut files, mut voids) = load_input(include_str!("test_input.txt")).unwrap();
         dump_map(&files, &voids);
         defragment(&mut files, &mut voids);
         assert_eq!(file_checksum(&files), 2858);
@@ -0,0 +1,136 @@
+use std::fmt::{self, Debug};
+
+use rand::{rngs::StdRng, SeedableRng};
+
+// Mismatch is the smallest input found on which two implementations disagree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch<T, A> {
+    pub input: T,
+    pub left: A,
+    pub right: A,
+}
+
+impl<T: Debug, A: Debug> fmt::Display for Mismatch<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "implementations disagree on {:?}: {:?} vs {:?}",
+            self.input, self.left, self.right
+        )
+    }
+}
+
+// cross_check feeds cases random inputs to both implementations, the first disagreement
+// is minimized by repeatedly taking the first smaller candidate that still disagrees
+pub fn cross_check<T, A>(
+    cases: usize,
+    seed: u64,
+    generate: impl Fn(&mut StdRng) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    left: impl Fn(&T) -> A,
+    right: impl Fn(&T) -> A,
+) -> Result<(), Mismatch<T, A>>
+where
+    A: PartialEq,
+{
+    let mut rng = StdRng::seed_from_u64(seed);
+    for _ in 0..cases {
+        let input = generate(&mut rng);
+        let (l, r) = (left(&input), right(&input));
+        if l == r {
+            continue;
+        }
+        let mut mismatch = Mismatch {
+            input,
+            left: l,
+            right: r,
+        };
+        'shrinking: loop {
+            for smaller in shrink(&mismatch.input) {
+                let (l, r) = (left(&smaller), right(&smaller));
+                if l != r {
+                    mismatch = Mismatch {
+                        input: smaller,
+                        left: l,
+                        right: r,
+                    };
+                    continue 'shrinking;
+                }
+            }
+            return Err(mismatch);
+        }
+    }
+    return Ok(());
+}
+
+// shrink_vec proposes the vector with one element left out, from the front
+pub fn shrink_vec<T: Clone>(v: &Vec<T>) -> Vec<Vec<T>> {
+    (0..v.len())
+        .map(|i| {
+            let mut smaller = v.clone();
+            smaller.remove(i);
+            smaller
+        })
+        .collect()
+}
+
+// shrink_num proposes smaller non-negative numbers, zero and halves first
+pub fn shrink_num(n: i64) -> Vec<i64> {
+    let mut smaller = Vec::new();
+    if n > 0 {
+        smaller.push(0);
+        if n / 2 > 0 {
+            smaller.push(n / 2);
+        }
+        if n - 1 > n / 2 {
+            smaller.push(n - 1);
+        }
+    }
+    return smaller;
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use super::*;
+
+    #[test]
+    fn check_minimized() {
+        // the "fast" sum ignores numbers above 50, the smallest counterexample is a single 51
+        let mismatch = cross_check(
+            100,
+            1,
+            |rng| (0..10).map(|_| rng.gen_range(0..100)).collect::<Vec<i64>>(),
+            |v| {
+                let mut smaller = shrink_vec(v);
+                for i in 0..v.len() {
+                    for n in shrink_num(v[i]) {
+                        let mut s = v.clone();
+                        s[i] = n;
+                        smaller.push(s);
+                    }
+                }
+                smaller
+            },
+            |v| v.iter().sum::<i64>(),
+            |v| v.iter().filter(|x| **x <= 50).sum::<i64>(),
+        )
+        .unwrap_err();
+        assert_eq!(vec![51], mismatch.input);
+        assert_eq!((51, 0), (mismatch.left, mismatch.right));
+    }
+
+    #[test]
+    fn check_agreement() {
+        let r = cross_check(
+            50,
+            1,
+            |rng| rng.gen_range(0..1000i64),
+            |n| shrink_num(*n),
+            |n| n * 2,
+            |n| n + n,
+        );
+        assert_eq!(Ok(()), r);
+    }
+}
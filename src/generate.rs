@@ -0,0 +1,513 @@
+use std::collections::HashSet;
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+use crate::{
+    day17::Day17,
+    day18::Day18,
+    grid::{Dir, Grid, Pos},
+    Answer, Solution,
+};
+
+// generate produces a random but valid puzzle input of the day, size scales it
+// (number of lines, records or side of the map), returns None for unknown days
+pub fn generate(day: u8, size: usize, seed: u64) -> Option<String> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(1);
+    let txt = match day {
+        1 => location_lists(&mut rng, size),
+        2 => reports(&mut rng, size),
+        3 => memory(&mut rng, size),
+        4 => word_search(&mut rng, size),
+        5 => print_queue(&mut rng, size),
+        6 => guard_map(&mut rng, size),
+        7 => equations(&mut rng, size),
+        8 => antennas(&mut rng, size),
+        9 => disk_map(&mut rng, size),
+        10 => topographic_map(&mut rng, size),
+        11 => stones(&mut rng, size),
+        12 => garden(&mut rng, size),
+        13 => claw_machines(&mut rng, size),
+        14 => robots(&mut rng, size),
+        15 => warehouse(&mut rng, size),
+        16 => maze(&mut rng, size).render(|c| *c),
+        17 => program(&mut rng, size),
+        18 => falling_bytes(&mut rng, size),
+        19 => towels(&mut rng, size),
+        20 => racetrack(&mut rng, size),
+        21 => door_codes(&mut rng, size),
+        _ => return None,
+    };
+    return Some(txt);
+}
+
+fn join(lines: impl Iterator<Item = String>) -> String {
+    lines.collect::<Vec<String>>().join("\n")
+}
+
+fn location_lists(rng: &mut StdRng, size: usize) -> String {
+    join((0..size).map(|_| {
+        format!(
+            "{}   {}",
+            rng.gen_range(10000..100000),
+            rng.gen_range(10000..100000)
+        )
+    }))
+}
+
+// reports are mostly safe runs with an occasional bad level
+fn reports(rng: &mut StdRng, size: usize) -> String {
+    join((0..size).map(|_| {
+        let len = rng.gen_range(5..=8);
+        let sign = if rng.gen_bool(0.5) { 1 } else { -1 };
+        let mut level: i32 = rng.gen_range(20..70);
+        let mut levels = Vec::new();
+        for _ in 0..len {
+            levels.push(level.to_string());
+            level += if rng.gen_bool(0.1) {
+                rng.gen_range(-4..=4)
+            } else {
+                sign * rng.gen_range(1..=3)
+            };
+        }
+        levels.join(" ")
+    }))
+}
+
+// memory is size instructions (valid or broken) separated by noise
+fn memory(rng: &mut StdRng, size: usize) -> String {
+    const NOISE: &[u8] = b"+-*/()[]{}<>!@#$%^&,;:'?_ whatmuldon";
+    let mut txt = String::new();
+    for _ in 0..size {
+        for _ in 0..rng.gen_range(0..8) {
+            txt.push(NOISE[rng.gen_range(0..NOISE.len())] as char);
+        }
+        let (a, b) = (rng.gen_range(1..1000), rng.gen_range(1..1000));
+        txt.push_str(&match rng.gen_range(0..10) {
+            0 => "do()".to_string(),
+            1 => "don't()".to_string(),
+            2 => format!("mul({a},{b}]"),
+            3 => format!("mul ( {a},{b})"),
+            _ => format!("mul({a},{b})"),
+        });
+    }
+    return txt;
+}
+
+fn random_map(rng: &mut StdRng, width: usize, height: usize, cells: &[char]) -> Grid<char> {
+    let cells = (0..width * height)
+        .map(|_| cells[rng.gen_range(0..cells.len())])
+        .collect();
+    return Grid::from_vec(width, height, cells);
+}
+
+fn word_search(rng: &mut StdRng, size: usize) -> String {
+    random_map(rng, size, size, &['X', 'M', 'A', 'S']).render(|c| *c)
+}
+
+// print_queue orders all pages by a random permutation, so every update has one correct order
+fn print_queue(rng: &mut StdRng, size: usize) -> String {
+    let mut pages: Vec<i32> = (10..100).collect();
+    pages.shuffle(rng);
+    pages.truncate(49);
+    let mut rules = Vec::new();
+    for i in 0..pages.len() {
+        for j in i + 1..pages.len() {
+            rules.push(format!("{}|{}", pages[i], pages[j]));
+        }
+    }
+    rules.shuffle(rng);
+    let updates = (0..size).map(|_| {
+        let len = rng.gen_range(2..=11) * 2 + 1;
+        let update: Vec<String> = pages
+            .choose_multiple(rng, len)
+            .map(i32::to_string)
+            .collect();
+        update.join(",")
+    });
+    return format!("{}\n\n{}", rules.join("\n"), join(updates));
+}
+
+// leaves tells whether the guard starting at pos walks off the map instead of looping
+fn leaves(map: &Grid<char>, mut pos: Pos) -> bool {
+    let mut dir = Dir::Up;
+    let mut seen = HashSet::new();
+    while seen.insert((pos, dir)) {
+        match map.neighbour(pos, dir) {
+            None => return true,
+            Some(next) if map[next] == '#' => dir = dir.turn_right(),
+            Some(next) => pos = next,
+        }
+    }
+    return false;
+}
+
+fn guard_map(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(2);
+    loop {
+        let mut map = random_map(rng, size, size, &['.'; 9]);
+        for p in map.positions().collect::<Vec<Pos>>() {
+            if rng.gen_bool(0.1) {
+                map[p] = '#';
+            }
+        }
+        let guard = (rng.gen_range(0..size as i32), rng.gen_range(0..size as i32));
+        if map[guard] == '.' && leaves(&map, guard) {
+            map[guard] = '^';
+            return map.render(|c| *c);
+        }
+    }
+}
+
+// equations are built from random operators, but some results are bumped to be unsolvable
+fn equations(rng: &mut StdRng, size: usize) -> String {
+    join((0..size).map(|_| {
+        let operands: Vec<i64> = (0..rng.gen_range(2..=6))
+            .map(|_| rng.gen_range(1..1000))
+            .collect();
+        let mut result = operands[0];
+        for x in &operands[1..] {
+            result = match rng.gen_range(0..3) {
+                0 => result + x,
+                1 => result * x,
+                _ => format!("{result}{x}").parse().unwrap(),
+            };
+        }
+        if rng.gen_bool(0.3) {
+            result += 1;
+        }
+        let operands: Vec<String> = operands.iter().map(i64::to_string).collect();
+        format!("{result}: {}", operands.join(" "))
+    }))
+}
+
+fn antennas(rng: &mut StdRng, size: usize) -> String {
+    const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let mut map = Grid::new(size, size, '.');
+    for _ in 0..(size * size / 40).max(2) {
+        let p = (rng.gen_range(0..size as i32), rng.gen_range(0..size as i32));
+        map[p] = FREQUENCIES[rng.gen_range(0..FREQUENCIES.len())] as char;
+    }
+    return map.render(|c| *c);
+}
+
+// disk_map has size digits, files are never empty
+fn disk_map(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|i| {
+            let min = if i % 2 == 0 { 1 } else { 0 };
+            char::from_digit(rng.gen_range(min..10), 10).unwrap()
+        })
+        .collect()
+}
+
+// topographic_map has smooth slopes, so that trails exist
+fn topographic_map(rng: &mut StdRng, size: usize) -> String {
+    let mut map = Grid::new(size, size, 0);
+    for p in map.positions().collect::<Vec<Pos>>() {
+        let up = map.get(Dir::Up.step(p)).copied();
+        let left = map.get(Dir::Left.step(p)).copied();
+        let base = match (up, left) {
+            (Some(u), Some(l)) if rng.gen_bool(0.5) => u.max(l),
+            (Some(u), Some(l)) => u.min(l),
+            (Some(h), None) | (None, Some(h)) => h,
+            (None, None) => 0,
+        };
+        map[p] = (base + rng.gen_range(-1..=1i32)).clamp(0, 9);
+    }
+    return map.render(|h| char::from_digit(*h as u32, 10).unwrap());
+}
+
+fn stones(rng: &mut StdRng, size: usize) -> String {
+    let stones: Vec<String> = (0..size)
+        .map(|_| rng.gen_range(0..1000000).to_string())
+        .collect();
+    return stones.join(" ");
+}
+
+// garden grows regions by copying plants of the neighbours
+fn garden(rng: &mut StdRng, size: usize) -> String {
+    let mut map = Grid::new(size, size, 'A');
+    for p in map.positions().collect::<Vec<Pos>>() {
+        let copy = [Dir::Up, Dir::Left]
+            .choose(rng)
+            .and_then(|d| map.get(d.step(p)).copied());
+        map[p] = match copy {
+            Some(plant) if rng.gen_bool(0.8) => plant,
+            _ => rng.gen_range(b'A'..=b'Z') as char,
+        };
+    }
+    return map.render(|c| *c);
+}
+
+// claw_machines have prizes reachable by some presses, unless moved by a random offset
+fn claw_machines(rng: &mut StdRng, size: usize) -> String {
+    let machines = (0..size).map(|_| {
+        let (ax, ay, bx, by) = (
+            rng.gen_range(10..100),
+            rng.gen_range(10..100),
+            rng.gen_range(10..100),
+            rng.gen_range(10..100),
+        );
+        let (a, b) = (rng.gen_range(0..=100), rng.gen_range(0..=100));
+        let offset = if rng.gen_bool(0.5) {
+            0
+        } else {
+            rng.gen_range(1..100)
+        };
+        format!(
+            "Button A: X+{ax}, Y+{ay}\nButton B: X+{bx}, Y+{by}\nPrize: X={}, Y={}",
+            a * ax + b * bx + offset,
+            a * ay + b * by
+        )
+    });
+    return machines.collect::<Vec<String>>().join("\n\n");
+}
+
+fn robots(rng: &mut StdRng, size: usize) -> String {
+    return robots_picture(rng, size).0;
+}
+
+// robots_picture has the robots line up as a triangle at a random second, which it returns;
+// with 50 or more, a tenth of them wander elsewhere
+fn robots_picture(rng: &mut StdRng, size: usize) -> (String, i64) {
+    let (w, h) = (101, 103);
+    let t: i64 = rng.gen_range(0..w * h);
+    let (cx, cy) = (rng.gen_range(10..w - 10), rng.gen_range(10..h - 10));
+    let stray = if size < 50 { 0 } else { size / 10 };
+    let lines = (0..size).map(|i| {
+        // moving robots keep the picture from showing up at any other second
+        let mut v = || loop {
+            let v: i64 = rng.gen_range(-99..100);
+            if v != 0 {
+                return v;
+            }
+        };
+        let (dx, dy) = (v(), v());
+        let (x, y) = if i < size - stray {
+            let row = rng.gen_range(0..8);
+            let (tx, ty) = (cx + rng.gen_range(-row..=row), cy + row - 4);
+            ((tx - dx * t).rem_euclid(w), (ty - dy * t).rem_euclid(h))
+        } else {
+            (rng.gen_range(0..w), rng.gen_range(0..h))
+        };
+        format!("p={x},{y} v={dx},{dy}")
+    });
+    return (join(lines), t);
+}
+
+// warehouse is a walled map with boxes followed by moves in lines of 1000
+fn warehouse(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(3);
+    let mut map = Grid::new(size, size, '#');
+    let last = size as i32 - 1;
+    for p in map.positions().collect::<Vec<Pos>>() {
+        if p.0 > 0 && p.1 > 0 && p.0 < last && p.1 < last {
+            map[p] = match rng.gen_range(0..10) {
+                0 => '#',
+                1 | 2 => 'O',
+                _ => '.',
+            };
+        }
+    }
+    map[(rng.gen_range(1..last), rng.gen_range(1..last))] = '@';
+    let moves: String = (0..size * 10)
+        .map(|_| Dir::ALL.choose(rng).unwrap().arrow())
+        .collect();
+    let moves: Vec<String> = moves
+        .as_bytes()
+        .chunks(1000)
+        .map(|l| String::from_utf8(l.to_vec()).unwrap())
+        .collect();
+    return format!("{}\n\n{}", map.render(|c| *c), moves.join("\n"));
+}
+
+// maze is a perfect maze carved by a depth-first walk, S bottom left and E top right
+fn maze(rng: &mut StdRng, size: usize) -> Grid<char> {
+    // cells sit on odd coordinates, so the side is odd
+    let size = size.max(5) | 1;
+    let mut map = Grid::new(size, size, '#');
+    let mut stack = vec![(1, 1)];
+    map[(1, 1)] = '.';
+    while let Some(&p) = stack.last() {
+        let mut dirs = Dir::ALL;
+        dirs.shuffle(rng);
+        let next = dirs.iter().find_map(|d| {
+            let wall = d.step(p);
+            let cell = d.step(wall);
+            (map.get(cell) == Some(&'#') && cell.0 > 0 && cell.1 > 0).then_some((wall, cell))
+        });
+        match next {
+            Some((wall, cell)) if (cell.0 as usize) < size - 1 && (cell.1 as usize) < size - 1 => {
+                map[wall] = '.';
+                map[cell] = '.';
+                stack.push(cell);
+            }
+            _ => {
+                stack.pop();
+            }
+        }
+    }
+    let last = size as i32 - 2;
+    map[(last, 1)] = 'S';
+    map[(1, last)] = 'E';
+    return map;
+}
+
+// program has the shape of the real ones: the output depends on A shifted by 3 bits per digit.
+// Only a few of the constants let the program print itself, so they are drawn until the
+// solver finds an A that does.
+fn program(rng: &mut StdRng, size: usize) -> String {
+    let digits = size.clamp(1, 16) as u32;
+    let a: u64 = rng.gen_range(8u64.pow(digits - 1)..8u64.pow(digits).max(8));
+    loop {
+        let (k1, k2) = (rng.gen_range(0..8), rng.gen_range(0..8));
+        let txt = format!(
+            "Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: 2,4,1,{k1},7,5,1,{k2},4,0,5,5,0,3,3,0"
+        );
+        let comp = Day17::parse(&txt).expect("generated program parses");
+        if Day17::part2(&comp) != Answer::Unsolved {
+            return txt;
+        }
+    }
+}
+
+// falling_bytes corrupt two thirds of a size x size memory space, never the start or the exit.
+// Part 1 lets half of the bytes fall that come before the first one cutting the exit off.
+fn falling_bytes(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(2) as i32;
+    let mut bytes: Vec<(i32, i32)> = (0..size)
+        .flat_map(|x| (0..size).map(move |y| (x, y)))
+        .filter(|p| *p != (0, 0) && *p != (size - 1, size - 1))
+        .collect();
+    bytes.shuffle(rng);
+    bytes.truncate((bytes.len() * 2 / 3).max(1));
+    let body = join(bytes.iter().map(|(x, y)| format!("{x},{y}")));
+    let all = format!("size={size} fallen={}\n{body}", bytes.len());
+    let input = Day18::parse(&all).expect("generated bytes parse");
+    let cut = match Day18::part2(&input) {
+        Answer::Coord(x, y) => bytes
+            .iter()
+            .position(|b| *b == (x as i32, y as i32))
+            .unwrap(),
+        _ => bytes.len(),
+    };
+    return format!("size={size} fallen={}\n{body}", cut / 2);
+}
+
+// towels are random stripes, most designs are made of them
+fn towels(rng: &mut StdRng, size: usize) -> String {
+    const COLOURS: &[u8] = b"wubrg";
+    let stripes = |rng: &mut StdRng, len: usize| -> String {
+        (0..len)
+            .map(|_| COLOURS[rng.gen_range(0..COLOURS.len())] as char)
+            .collect()
+    };
+    let mut patterns: Vec<String> = (0..size.max(5))
+        .map(|_| {
+            let len = rng.gen_range(1..=8);
+            stripes(rng, len)
+        })
+        .collect();
+    patterns.sort();
+    patterns.dedup();
+    patterns.shuffle(rng);
+    let designs = (0..size).map(|_| {
+        if rng.gen_bool(0.2) {
+            let len = rng.gen_range(20..60);
+            return stripes(rng, len);
+        }
+        let mut design = String::new();
+        while design.len() < 20 {
+            design.push_str(patterns.choose(rng).unwrap());
+        }
+        design
+    });
+    return format!("{}\n\n{}", patterns.join(", "), join(designs));
+}
+
+// racetrack keeps only the path from S to E of a random maze, so there is a single track
+fn racetrack(rng: &mut StdRng, size: usize) -> String {
+    let maze = maze(rng, size);
+    let start = maze.find(|c| *c == 'S').unwrap();
+    let mut track = Grid::new(maze.width(), maze.height(), '#');
+    let mut path = vec![start];
+    let mut seen = HashSet::from([start]);
+    while maze[*path.last().unwrap()] != 'E' {
+        let p = *path.last().unwrap();
+        let next = maze
+            .neighbours4(p)
+            .map(|(_, n)| n)
+            .find(|n| maze[*n] != '#' && !seen.contains(n));
+        match next {
+            Some(n) => {
+                seen.insert(n);
+                path.push(n);
+            }
+            None => {
+                path.pop();
+            }
+        }
+    }
+    for p in &path {
+        track[*p] = maze[*p];
+    }
+    return track.render(|c| *c);
+}
+
+fn door_codes(rng: &mut StdRng, size: usize) -> String {
+    join((0..size).map(|_| format!("{:03}A", rng.gen_range(0..1000))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day14::Day14;
+
+    #[test]
+    fn check_generate() {
+        for day in 1..=21 {
+            let txt = generate(day, 12, 7).unwrap();
+            assert_eq!(Some(&txt), generate(day, 12, 7).as_ref());
+            match crate::run(day, &txt, None) {
+                Some(Ok(solved)) => {
+                    // day 21 part 2 is not solved at all
+                    for p in solved.parts.iter().filter(|p| (p.day, p.part) != (21, 2)) {
+                        assert_ne!(
+                            Answer::Unsolved,
+                            p.answer,
+                            "day {day} part {}\n{txt}",
+                            p.part
+                        );
+                    }
+                }
+                other => panic!("day {day} rejects generated input: {other:?}\n{txt}"),
+            }
+        }
+        assert!(generate(42, 12, 7).is_none());
+    }
+
+    #[test]
+    fn check_robots() {
+        for (seed, size) in [(1, 12), (2, 12), (3, 100), (4, 500)] {
+            let (txt, t) = robots_picture(&mut StdRng::seed_from_u64(seed), size);
+            let input = Day14::parse(&txt).unwrap();
+            assert_eq!(Answer::Num(t), Day14::part2(&input), "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn check_racetrack() {
+        let track = racetrack(&mut StdRng::seed_from_u64(1), 15);
+        let map = Grid::parse(20, &track, "a track cell", Some).unwrap();
+        // every track cell but S and E has exactly two track neighbours
+        for (p, c) in map.iter() {
+            let n = map.neighbours4(p).filter(|(_, n)| map[*n] != '#').count();
+            match c {
+                '#' => {}
+                'S' | 'E' => assert_eq!(1, n),
+                _ => assert_eq!(2, n),
+            }
+        }
+    }
+}
@@ -0,0 +1,294 @@
+use std::ops::{Index, IndexMut};
+
+use crate::parse::{self, ParseError};
+
+// Pos is (row, column), signed so that stepping outside of the grid is representable
+pub type Pos = (i32, i32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir {
+    // clockwise, starting from up
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+
+    pub fn delta(self) -> Pos {
+        match self {
+            Dir::Up => (-1, 0),
+            Dir::Right => (0, 1),
+            Dir::Down => (1, 0),
+            Dir::Left => (0, -1),
+        }
+    }
+
+    // step returns the position next to p in this direction, it may be outside of the grid
+    pub fn step(self, p: Pos) -> Pos {
+        let d = self.delta();
+        (p.0 + d.0, p.1 + d.1)
+    }
+
+    pub fn turn_right(self) -> Dir {
+        Dir::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Dir {
+        Dir::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Dir {
+        Dir::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn from_arrow(c: char) -> Option<Dir> {
+        match c {
+            '^' => Some(Dir::Up),
+            '>' => Some(Dir::Right),
+            'v' => Some(Dir::Down),
+            '<' => Some(Dir::Left),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Dir::Up => '^',
+            Dir::Right => '>',
+            Dir::Down => 'v',
+            Dir::Left => '<',
+        }
+    }
+}
+
+// offsets to all 8 surrounding cells, clockwise starting from up
+pub const AROUND: [Pos; 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+// Grid is a rectangular map stored row by row in a single vector
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "grid size mismatch");
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // parse reads a character map, cell maps every character to a cell or rejects it;
+    // rows must be of equal length
+    pub fn parse(
+        day: u8,
+        txt: &str,
+        expected: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in parse::lines(day, txt) {
+            for (col, c) in line.text.char_indices() {
+                match cell(c) {
+                    None => return Err(line.error(&line.text[col..col + c.len_utf8()], expected)),
+                    Some(v) => cells.push(v),
+                }
+            }
+            let len = line.text.chars().count();
+            match width {
+                None => width = Some(len),
+                Some(w) if w != len => {
+                    return Err(line.error_at_end(&format!("a row of {w} cells")));
+                }
+                _ => {}
+            }
+            height += 1;
+        }
+        return Ok(Self::from_vec(width.unwrap_or(0), height, cells));
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Pos) -> bool {
+        p.0 >= 0 && p.1 >= 0 && (p.0 as usize) < self.height && (p.1 as usize) < self.width
+    }
+
+    fn offset(&self, p: Pos) -> Option<usize> {
+        if self.contains(p) {
+            Some(p.0 as usize * self.width + p.1 as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, p: Pos) -> Option<&T> {
+        self.offset(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Pos) -> Option<&mut T> {
+        self.offset(p).map(|i| &mut self.cells[i])
+    }
+
+    // neighbour returns position next to p in the given direction if it is inside of the grid
+    pub fn neighbour(&self, p: Pos, d: Dir) -> Option<Pos> {
+        let n = d.step(p);
+        if self.contains(n) {
+            Some(n)
+        } else {
+            None
+        }
+    }
+
+    // neighbours4 iterates over orthogonally adjacent positions inside of the grid
+    pub fn neighbours4(&self, p: Pos) -> impl Iterator<Item = (Dir, Pos)> + '_ {
+        Dir::ALL
+            .into_iter()
+            .filter_map(move |d| self.neighbour(p, d).map(|n| (d, n)))
+    }
+
+    // neighbours8 iterates over all adjacent positions (including diagonals) inside of the grid
+    pub fn neighbours8(&self, p: Pos) -> impl Iterator<Item = Pos> + '_ {
+        AROUND
+            .into_iter()
+            .map(move |d| (p.0 + d.0, p.1 + d.1))
+            .filter(|n| self.contains(*n))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let (width, height) = (self.width as i32, self.height as i32);
+        (0..height).flat_map(move |r| (0..width).map(move |c| (r, c)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    // find returns position of the first cell (row by row) matching the predicate
+    pub fn find(&self, pred: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, v)| pred(v)).map(|(p, _)| p)
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    // render turns the grid back into the character map
+    pub fn render(&self, f: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&f).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Pos) -> &T {
+        match self.get(p) {
+            Some(v) => v,
+            None => panic!("{p:?} is outside of {}x{} grid", self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, p: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(p) {
+            Some(v) => v,
+            None => panic!("{p:?} is outside of {width}x{height} grid"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_neighbour() {
+        let g = Grid::new(2, 2, 0);
+        assert_eq!(None, g.neighbour((1, 1), Dir::Down));
+        assert_eq!(None, g.neighbour((0, 0), Dir::Left));
+        assert_eq!(Some((1, 0)), g.neighbour((0, 0), Dir::Down));
+        let g = Grid::new(3, 4, 0);
+        assert_eq!(None, g.neighbour((2, 2), Dir::Right));
+        assert_eq!(Some((3, 2)), g.neighbour((2, 2), Dir::Down));
+        assert_eq!(
+            vec![(Dir::Right, (0, 1)), (Dir::Down, (1, 0))],
+            g.neighbours4((0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(8, g.neighbours8((1, 1)).count());
+        assert_eq!(3, g.neighbours8((3, 2)).count());
+    }
+
+    #[test]
+    fn check_turns() {
+        for d in Dir::ALL {
+            assert_eq!(d, d.turn_right().turn_left());
+            assert_eq!(d.reverse(), d.turn_right().turn_right());
+            assert_eq!(Some(d), Dir::from_arrow(d.arrow()));
+        }
+        assert_eq!(Dir::Right, Dir::Up.turn_right());
+        assert_eq!(Dir::Up, Dir::Left.turn_right());
+    }
+
+    #[test]
+    fn check_parse_render() {
+        let txt = "#.#\n.S.\n";
+        let g = Grid::parse(1, txt, "a cell", Some).unwrap();
+        assert_eq!((3, 2), (g.width(), g.height()));
+        assert_eq!('S', g[(1, 1)]);
+        assert_eq!(Some((1, 1)), g.find(|c| *c == 'S'));
+        assert_eq!(txt.trim_end(), g.render(|c| *c));
+
+        let err = Grid::parse(1, "..\n.", "a cell", Some).unwrap_err();
+        assert_eq!((2, 2), (err.line, err.column));
+        let err = Grid::parse(1, "..\n.x", "a dot", |c| (c == '.').then_some(c)).unwrap_err();
+        assert_eq!((2, 2, "x"), (err.line, err.column, err.found.as_str()));
+    }
+}
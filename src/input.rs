@@ -0,0 +1,112 @@
+use std::{
+    env, fmt, fs,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+// environment variable pointing to a directory with inputs named dayN.txt (or dayN/input.txt)
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+// Source tells where the puzzle input is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    // from_arg treats "-" as stdin and anything else as a file path
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Source::Stdin
+        } else {
+            Source::File(PathBuf::from(arg))
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Source::Stdin => {
+                let mut txt = String::new();
+                io::stdin().read_to_string(&mut txt)?;
+                Ok(txt)
+            }
+            Source::File(path) => fs::read_to_string(path),
+        }
+    }
+
+    // reader streams the input instead of reading it whole
+    pub fn reader(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+            Source::File(path) => Ok(Box::new(BufReader::new(fs::File::open(path)?))),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "stdin"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+// candidates lists files that may contain the input of the day inside the given directory
+fn candidates(dir: &Path, day: u8) -> [PathBuf; 2] {
+    [
+        dir.join(format!("day{day}.txt")),
+        dir.join(format!("day{day}")).join("input.txt"),
+    ]
+}
+
+// locate finds the input of the day when no explicit source was given:
+// first in $AOC_INPUT_DIR, then among the inputs committed to the repository
+pub fn locate(day: u8) -> io::Result<Source> {
+    let mut dirs = Vec::new();
+    if let Some(dir) = env::var_os(INPUT_DIR_VAR) {
+        dirs.push(PathBuf::from(dir));
+    }
+    dirs.push(Path::new(env!("CARGO_MANIFEST_DIR")).join("src"));
+    for dir in &dirs {
+        for path in candidates(dir, day) {
+            if path.is_file() {
+                return Ok(Source::File(path));
+            }
+        }
+    }
+    return Err(io::Error::new(
+        io::ErrorKind::NotFound,
+        format!(
+            "no input for day {day} in {}",
+            dirs.iter()
+                .map(|d| d.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_locate() {
+        assert_eq!(
+            locate(16).unwrap(),
+            Source::File(Path::new(env!("CARGO_MANIFEST_DIR")).join("src/day16/input.txt"))
+        );
+        assert!(locate(42).is_err());
+    }
+
+    #[test]
+    fn check_from_arg() {
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+        assert_eq!(
+            Source::from_arg("foo.txt"),
+            Source::File(PathBuf::from("foo.txt"))
+        );
+    }
+}
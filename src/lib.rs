@@ -0,0 +1,209 @@
+// the solutions favour explicit `return`s, `&Vec` arguments and index loops
+#![allow(
+    clippy::needless_return,
+    clippy::ptr_arg,
+    clippy::needless_range_loop,
+    clippy::explicit_counter_loop,
+    clippy::assign_op_pattern,
+    clippy::redundant_static_lifetimes
+)]
+
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+use serde::{Serialize, Serializer};
+
+use parse::ParseError;
+
+pub mod bench;
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod differential;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod verify;
+
+// Answer serializes to a JSON number, string, [x, y] pair or null when unsolved
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Num(i64),
+    Text(String),
+    Coord(i64, i64),
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Num(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Coord(x, y) => write!(f, "{x},{y}"),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(v: $t) -> Self {
+                Answer::Num(v as i64)
+            }
+        })*
+    };
+}
+answer_from_int!(i32, i64, u64, usize);
+
+impl From<String> for Answer {
+    fn from(v: String) -> Self {
+        Answer::Text(v)
+    }
+}
+
+// Solution is implemented by every day: input is parsed once and then shared by both parts
+pub trait Solution {
+    const DAY: u8;
+    type Input;
+    fn parse(txt: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+
+    // diagnostics explain how the answer to the part was obtained, e.g. sizes derived from the input
+    fn diagnostics(_input: &Self::Input, _part: u8) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+}
+
+// PartResult is the answer to one part of the puzzle along with the time spent solving it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PartResult {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    #[serde(rename = "duration_ns", serialize_with = "nanos")]
+    pub duration: Duration,
+    #[serde(skip_serializing_if = "Vec::is_empty", serialize_with = "pairs")]
+    pub diagnostics: Vec<(&'static str, String)>,
+}
+
+fn nanos<S: Serializer>(d: &Duration, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_u128(d.as_nanos())
+}
+
+// pairs serializes diagnostics as an object keeping their order
+fn pairs<S: Serializer>(v: &Vec<(&'static str, String)>, s: S) -> Result<S::Ok, S::Error> {
+    s.collect_map(v.iter().map(|(k, v)| (k, v)))
+}
+
+// Solved holds results of the requested parts along with time spent parsing the input
+#[derive(Debug, Clone)]
+pub struct Solved {
+    pub parse: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+// solve parses the input and runs requested parts (or both if part is None)
+pub fn solve<S: Solution>(txt: &str, part: Option<u8>) -> Result<Solved, ParseError> {
+    let start = Instant::now();
+    let input = S::parse(txt)?;
+    let parse = start.elapsed();
+    let mut parts = Vec::new();
+    for p in [1, 2] {
+        if part.is_some_and(|part| part != p) {
+            continue;
+        }
+        let start = Instant::now();
+        let answer = if p == 1 {
+            S::part1(&input)
+        } else {
+            S::part2(&input)
+        };
+        parts.push(PartResult {
+            day: S::DAY,
+            part: p,
+            answer,
+            duration: start.elapsed(),
+            diagnostics: S::diagnostics(&input, p),
+        });
+    }
+    return Ok(Solved { parse, parts });
+}
+
+// run dispatches to the solver of the given day, returns None for unknown days
+pub fn run(day: u8, txt: &str, part: Option<u8>) -> Option<Result<Solved, ParseError>> {
+    let answers = match day {
+        1 => solve::<day1::Day1>(txt, part),
+        2 => solve::<day2::Day2>(txt, part),
+        3 => solve::<day3::Day3>(txt, part),
+        4 => solve::<day4::Day4>(txt, part),
+        5 => solve::<day5::Day5>(txt, part),
+        6 => solve::<day6::Day6>(txt, part),
+        7 => solve::<day7::Day7>(txt, part),
+        8 => solve::<day8::Day8>(txt, part),
+        9 => solve::<day9::Day9>(txt, part),
+        10 => solve::<day10::Day10>(txt, part),
+        11 => solve::<day11::Day11>(txt, part),
+        12 => solve::<day12::Day12>(txt, part),
+        13 => solve::<day13::Day13>(txt, part),
+        14 => solve::<day14::Day14>(txt, part),
+        15 => solve::<day15::Day15>(txt, part),
+        16 => solve::<day16::Day16>(txt, part),
+        17 => solve::<day17::Day17>(txt, part),
+        18 => solve::<day18::Day18>(txt, part),
+        19 => solve::<day19::Day19>(txt, part),
+        20 => solve::<day20::Day20>(txt, part),
+        21 => solve::<day21::Day21>(txt, part),
+        _ => return None,
+    };
+    return Some(answers);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_json() {
+        let mut p = PartResult {
+            day: 18,
+            part: 2,
+            answer: Answer::Coord(6, 1),
+            duration: Duration::from_nanos(1500),
+            diagnostics: vec![("memory size", "7x7".to_string())],
+        };
+        assert_eq!(
+            r#"{"day":18,"part":2,"answer":[6,1],"duration_ns":1500,"diagnostics":{"memory size":"7x7"}}"#,
+            serde_json::to_string(&p).unwrap()
+        );
+        p.answer = Answer::Unsolved;
+        p.diagnostics.clear();
+        assert_eq!(
+            r#"{"day":18,"part":2,"answer":null,"duration_ns":1500}"#,
+            serde_json::to_string(&p).unwrap()
+        );
+    }
+}
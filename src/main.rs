@@ -0,0 +1,791 @@
+use std::{
+    io::{Cursor, Read},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+use aoc2024::{
+    bench,
+    day1::{self, Metric, Overflow},
+    day2::{self, Day2, Monotonic, Rules},
+    day3,
+    day4::{self, Day4, Mask},
+    day5::{self, Day5},
+    generate,
+    input::{self, Source},
+    verify, Solution,
+};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve the puzzle of the given day
+    Run {
+        day: u8,
+        /// Run only this part (both by default)
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle input file, "-" for stdin
+        /// (looked up in $AOC_INPUT_DIR or among committed inputs by default)
+        #[arg(short, long)]
+        input: Option<String>,
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Solve the puzzle repeatedly and report min/median/max time of every phase
+    Bench {
+        day: u8,
+        /// Run only this part (both by default)
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle input file, "-" for stdin
+        #[arg(short, long)]
+        input: Option<String>,
+        /// Number of runs
+        #[arg(short, long, default_value_t = 10)]
+        runs: usize,
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Compare columns of numbers (like day 1 lists) by the given metrics, reading them as a stream;
+    /// more than two columns are compared pairwise
+    Compare {
+        /// Comma separated metrics: l1, l2 (squared), similarity, matches (all by default)
+        #[arg(short, long, value_delimiter = ',')]
+        metric: Vec<Metric>,
+        /// Input file, "-" for stdin (day 1 input by default)
+        #[arg(short, long)]
+        input: Option<String>,
+        /// Read the numbers as unsigned 64-bit instead of signed
+        #[arg(short, long)]
+        unsigned: bool,
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Tell why each report (day 2) is unsafe and which levels can be removed to make it safe
+    Reports {
+        /// Puzzle input file, "-" for stdin
+        #[arg(short, long)]
+        input: Option<String>,
+        /// Number of levels that may be removed from a report
+        #[arg(short, long, default_value_t = 1)]
+        removals: usize,
+        /// Print only the reports that are unsafe as they are
+        #[arg(long)]
+        unsafe_only: bool,
+        #[command(flatten)]
+        rules: RuleArgs,
+    },
+    /// Add up the muls of corrupted memory (day 3) read as a stream, in constant memory
+    Scan {
+        /// Puzzle input file, "-" for stdin
+        #[arg(short, long)]
+        input: Option<String>,
+        /// Bytes read at once
+        #[arg(long, default_value_t = day3::CHUNK)]
+        chunk: usize,
+        /// Print every instruction with its byte offset, whether it was enabled and the running total
+        #[arg(long)]
+        explain: bool,
+        /// Print the memory with counted muls, skipped muls and disabled spans highlighted
+        /// (reads the whole memory)
+        #[arg(long)]
+        highlight: bool,
+    },
+    /// Count words and 2D masks in a word search (day 4)
+    Search {
+        /// Puzzle input file, "-" for stdin
+        #[arg(short, long)]
+        input: Option<String>,
+        /// Comma separated words looked for in all 8 directions
+        /// (XMAS and the X of two MAS when neither words nor a mask are given)
+        #[arg(short, long, value_delimiter = ',')]
+        words: Vec<String>,
+        /// File with a mask of letters, `.` for any letter, looked for in all rotations and
+        /// reflections
+        #[arg(short, long)]
+        mask: Option<PathBuf>,
+        /// Print the grid with the matched letters highlighted
+        #[arg(long)]
+        show: bool,
+        /// Show unmatched letters as `.` instead of using colours
+        #[arg(long)]
+        plain: bool,
+        /// List every match with its start (row, column) and direction
+        #[arg(long)]
+        list: bool,
+    },
+    /// Tell which rules each update (day 5) breaks and how fixing it moves its pages
+    Queue {
+        /// Puzzle input file, "-" for stdin
+        #[arg(short, long)]
+        input: Option<String>,
+        /// Also print the updates that are in order
+        #[arg(long)]
+        all: bool,
+    },
+    /// Print a random puzzle input of the given day, e.g. to pipe into `run --input -`
+    Generate {
+        day: u8,
+        /// Number of lines or records, or side of the map
+        #[arg(short, long, default_value_t = 100)]
+        size: usize,
+        /// Seed of the random generator (random by default)
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Check answers of all inputs listed in the known-answers manifest
+    Verify {
+        /// Check only inputs of this day
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// Also check cases marked as slow
+        #[arg(long)]
+        slow: bool,
+        /// Known-answers manifest (answers.toml of the repository by default)
+        #[arg(short, long)]
+        manifest: Option<PathBuf>,
+    },
+}
+
+// RuleArgs are the safety rules of reports, the puzzle ones by default
+#[derive(Args)]
+struct RuleArgs {
+    /// Smallest allowed change between neighbouring levels
+    #[arg(long, default_value_t = Rules::PUZZLE.min_step)]
+    min_step: i32,
+    /// Largest allowed change between neighbouring levels
+    #[arg(long, default_value_t = Rules::PUZZLE.max_step)]
+    max_step: i32,
+    /// "strict" (levels never stay equal) or "non-strict" (equal levels pass with --min-step 0
+    /// or --allow-plateau)
+    #[arg(long, default_value = "strict")]
+    monotonic: Monotonic,
+    /// Let neighbouring levels be equal regardless of --min-step (needs non-strict)
+    #[arg(long)]
+    allow_plateau: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    /// One line of comma separated values per part (or phase when benchmarking)
+    Csv,
+    /// One JSON object per part (or phase when benchmarking)
+    Json,
+}
+
+// source is the given input or the located input of the day
+fn source(day: u8, input: Option<String>) -> Result<Source, String> {
+    match input {
+        Some(arg) => Ok(Source::from_arg(&arg)),
+        None => input::locate(day).map_err(|e| e.to_string()),
+    }
+}
+
+// read_input reads the input from the given source or locates the input of the day
+fn read_input(day: u8, input: Option<String>) -> Result<String, String> {
+    let source = source(day, input)?;
+    source
+        .read()
+        .map_err(|e| format!("cannot read {source}: {e}"))
+}
+
+fn run(day: u8, part: Option<u8>, txt: &str, format: Format) -> ExitCode {
+    match aoc2024::run(day, txt, part) {
+        None => {
+            eprintln!("day {day} is not solved");
+            return ExitCode::FAILURE;
+        }
+        Some(Err(e)) => {
+            eprintln!("{}", e.diagnostic(txt));
+            return ExitCode::FAILURE;
+        }
+        Some(Ok(solved)) => {
+            if format == Format::Csv {
+                println!("day,part,answer,duration_ns");
+            }
+            for p in &solved.parts {
+                match format {
+                    Format::Text => {
+                        println!(
+                            "day {day} part {}: {} ({:.1?})",
+                            p.part, p.answer, p.duration
+                        );
+                        for (name, value) in &p.diagnostics {
+                            println!("  {name}: {value}");
+                        }
+                    }
+                    // answers like "4,3,7" need quoting
+                    Format::Csv => println!(
+                        "{},{},\"{}\",{}",
+                        p.day,
+                        p.part,
+                        p.answer.to_string().replace('"', "\"\""),
+                        p.duration.as_nanos()
+                    ),
+                    Format::Json => println!("{}", serde_json::to_string(p).unwrap()),
+                }
+            }
+            if format == Format::Text {
+                println!("day {day} parse: {:.1?}", solved.parse);
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn bench(day: u8, part: Option<u8>, txt: &str, runs: usize, format: Format) -> ExitCode {
+    let b = match bench::bench(day, txt, part, runs) {
+        None => {
+            eprintln!("day {day} is not solved");
+            return ExitCode::FAILURE;
+        }
+        Some(Err(e)) => {
+            eprintln!("{}", e.diagnostic(txt));
+            return ExitCode::FAILURE;
+        }
+        Some(Ok(b)) => b,
+    };
+    match format {
+        Format::Text => println!("day {day}, {} runs", b.runs),
+        Format::Csv => println!("day,phase,runs,min_ns,median_ns,max_ns"),
+        Format::Json => {}
+    }
+    if format == Format::Text {
+        println!(
+            "{:<6} {:>10} {:>10} {:>10}",
+            "phase", "min", "median", "max"
+        );
+    }
+    for (phase, s) in &b.phases {
+        match format {
+            Format::Text => println!(
+                "{:<6} {:>10} {:>10} {:>10}",
+                phase,
+                format!("{:.1?}", s.min),
+                format!("{:.1?}", s.median),
+                format!("{:.1?}", s.max)
+            ),
+            Format::Csv => println!(
+                "{},{},{},{},{},{}",
+                b.day,
+                phase,
+                b.runs,
+                s.min.as_nanos(),
+                s.median.as_nanos(),
+                s.max.as_nanos()
+            ),
+            Format::Json => println!(
+                "{}",
+                serde_json::json!({
+                    "day": b.day,
+                    "phase": phase,
+                    "runs": b.runs,
+                    "min_ns": s.min.as_nanos(),
+                    "median_ns": s.median.as_nanos(),
+                    "max_ns": s.max.as_nanos(),
+                })
+            ),
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+// shown prints a measured value, overflows are reported once at the end
+fn shown(measured: &Result<i64, Overflow>) -> String {
+    match measured {
+        Ok(n) => n.to_string(),
+        Err(_) => "overflow".to_string(),
+    }
+}
+
+// report prints the metrics of two columns, or their matrices for more columns
+fn report<V: day1::Value>(columns: Vec<Vec<V>>, metrics: Vec<Metric>, format: Format) -> ExitCode {
+    let metrics = if metrics.is_empty() {
+        Metric::ALL.to_vec()
+    } else {
+        metrics
+    };
+    let pairwise = columns.len() > 2;
+    if format == Format::Csv {
+        if pairwise {
+            println!("metric,column,other,value");
+        } else {
+            println!("metric,value");
+        }
+    }
+    let mut overflows: Vec<Overflow> = Vec::new();
+    for m in metrics {
+        if !pairwise {
+            let value = m.measure(&columns[0], &columns[1]);
+            match format {
+                Format::Text => println!("{}: {}", m.name(), shown(&value)),
+                Format::Csv => println!("{},{}", m.name(), shown(&value)),
+                Format::Json => println!(
+                    "{}",
+                    serde_json::json!({ "metric": m.name(), "value": value.ok() })
+                ),
+            }
+            overflows.extend(value.err());
+            continue;
+        }
+        let matrix = m.matrix(&columns);
+        match format {
+            Format::Text => {
+                println!("{}:", m.name());
+                for row in &matrix {
+                    let row: Vec<String> =
+                        row.iter().map(|v| format!("{:>12}", shown(v))).collect();
+                    println!("  {}", row.join(" "));
+                }
+            }
+            Format::Csv => {
+                for (i, row) in matrix.iter().enumerate() {
+                    for (j, v) in row.iter().enumerate() {
+                        println!("{},{},{},{}", m.name(), i + 1, j + 1, shown(v));
+                    }
+                }
+            }
+            Format::Json => {
+                let matrix: Vec<Vec<Option<i64>>> = matrix
+                    .iter()
+                    .map(|row| row.iter().map(|v| v.ok()).collect())
+                    .collect();
+                println!(
+                    "{}",
+                    serde_json::json!({ "metric": m.name(), "matrix": matrix })
+                );
+            }
+        }
+        if let Some(e) = matrix.iter().flatten().find_map(|v| v.err()) {
+            overflows.push(e);
+        }
+    }
+    for e in &overflows {
+        eprintln!("{e}");
+    }
+    if !overflows.is_empty() {
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+fn compare(
+    metrics: Vec<Metric>,
+    input: Option<String>,
+    unsigned: bool,
+    format: Format,
+) -> ExitCode {
+    let source = match source(1, input) {
+        Ok(source) => source,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let reader = match source.reader() {
+        Ok(reader) => reader,
+        Err(e) => {
+            eprintln!("cannot read {source}: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let reported = if unsigned {
+        day1::read_columns::<u64>(reader, None).map(|columns| report(columns, metrics, format))
+    } else {
+        day1::read_columns::<i64>(reader, None).map(|columns| report(columns, metrics, format))
+    };
+    match reported {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("cannot compare {source}: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn reports(txt: &str, removals: usize, unsafe_only: bool, rules: RuleArgs) -> ExitCode {
+    let rules = match Rules::new(
+        rules.min_step,
+        rules.max_step,
+        rules.monotonic,
+        rules.allow_plateau,
+    ) {
+        Ok(rules) => rules,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let reports = match Day2::parse(txt) {
+        Ok(reports) => reports,
+        Err(e) => {
+            eprintln!("{}", e.diagnostic(txt));
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut safe = 0;
+    for (i, r) in reports.iter().enumerate() {
+        let d = day2::diagnose(r, removals, &rules);
+        if d.safe() {
+            safe += 1;
+        }
+        if unsafe_only && d.problem.is_none() {
+            continue;
+        }
+        let levels: Vec<String> = r.iter().map(|l| l.to_string()).collect();
+        let verdict = match (&d.problem, &d.removed) {
+            (None, _) => "safe".to_string(),
+            (Some(p), None) => format!("unsafe, {p}"),
+            (Some(p), Some(removed)) => {
+                let removed: Vec<String> = removed
+                    .iter()
+                    .map(|&l| format!("level {} ({})", l + 1, r[l]))
+                    .collect();
+                format!("safe without {}, {p}", removed.join(" and "))
+            }
+        };
+        println!("line {}: {}: {verdict}", i + 1, levels.join(" "));
+    }
+    println!("{safe} of {} reports safe", reports.len());
+    ExitCode::SUCCESS
+}
+
+fn scan(input: Option<String>, chunk: usize, explain: bool, highlight: bool) -> ExitCode {
+    let source = match source(3, input) {
+        Ok(source) => source,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    // highlighting needs the whole memory, otherwise it is streamed
+    let reader: Box<dyn Read> = if highlight {
+        match source.read() {
+            Ok(txt) => {
+                print!("{}", day3::highlight(&txt));
+                Box::new(Cursor::new(txt))
+            }
+            Err(e) => {
+                eprintln!("cannot read {source}: {e}");
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        match source.reader() {
+            Ok(reader) => reader,
+            Err(e) => {
+                eprintln!("cannot read {source}: {e}");
+                return ExitCode::FAILURE;
+            }
+        }
+    };
+    if explain {
+        println!(
+            "{:>10}  {:<14} {:<8} {:>12}",
+            "offset", "instruction", "enabled", "total"
+        );
+    }
+    let (mut all, mut enabled) = (day3::Machine::new(false), day3::Machine::new(true));
+    for lexed in day3::Scanner::new(reader, chunk) {
+        match lexed {
+            Ok((offset, op)) => {
+                all.run(op);
+                let step = enabled.step(offset, op);
+                if explain {
+                    let state = match op {
+                        day3::Op::Mul(_, _) if step.counted => "yes",
+                        day3::Op::Mul(_, _) => "no",
+                        _ if step.enabled => "on",
+                        _ => "off",
+                    };
+                    println!(
+                        "{:>10}  {:<14} {:<8} {:>12}",
+                        step.offset,
+                        step.op.to_string(),
+                        state,
+                        step.total
+                    );
+                }
+            }
+            Err(e) => {
+                eprintln!("cannot read {source}: {e}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    println!("all muls: {}", all.total);
+    println!("enabled muls: {}", enabled.total);
+    ExitCode::SUCCESS
+}
+
+// SearchOutput tells what to print besides the counts
+struct SearchOutput {
+    show: bool,
+    plain: bool,
+    list: bool,
+}
+
+fn search(txt: &str, words: Vec<String>, mask: Option<PathBuf>, out: SearchOutput) -> ExitCode {
+    let grid = match Day4::parse(txt) {
+        Ok(grid) => grid,
+        Err(e) => {
+            eprintln!("{}", e.diagnostic(txt));
+            return ExitCode::FAILURE;
+        }
+    };
+    // without words nor mask both puzzle parts are searched for
+    let (words, mask) = if words.is_empty() && mask.is_none() {
+        (vec![day4::XMAS.to_string()], Some(day4::X_MAS.to_string()))
+    } else {
+        let mask = match mask.map(|path| std::fs::read_to_string(&path).map_err(|e| (path, e))) {
+            None => None,
+            Some(Ok(txt)) => Some(txt),
+            Some(Err((path, e))) => {
+                eprintln!("cannot read {}: {e}", path.display());
+                return ExitCode::FAILURE;
+            }
+        };
+        (words, mask)
+    };
+    let mask: Mask = match mask.map(|txt| txt.parse()) {
+        None => Mask {
+            variants: Vec::new(),
+        },
+        Some(Ok(mask)) => mask,
+        Some(Err(e)) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let matches = day4::find_words(&grid, &words);
+    let mask_matches = day4::find_mask(&grid, &mask);
+    if out.show {
+        let covered = day4::matched(&grid, &words, &matches, &mask, &mask_matches);
+        let colour = !out.plain && console::colors_enabled();
+        println!("{}\n", day4::render(&grid, &covered, colour));
+    }
+    if out.list {
+        for m in &matches {
+            println!(
+                "{} at {:?} going {}",
+                words[m.word],
+                m.start,
+                day4::direction(m.dir)
+            );
+        }
+        for (corner, v) in &mask_matches {
+            println!("mask variant {} at {corner:?}", v + 1);
+        }
+    }
+    for (i, w) in words.iter().enumerate() {
+        println!("{w}: {}", matches.iter().filter(|m| m.word == i).count());
+    }
+    if !mask.variants.is_empty() {
+        println!(
+            "mask ({} variants): {}",
+            mask.variants.len(),
+            mask_matches.len()
+        );
+    }
+    ExitCode::SUCCESS
+}
+
+fn queue(txt: &str, all: bool) -> ExitCode {
+    let (rules, manuals) = match Day5::parse(txt) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e.diagnostic(txt));
+            return ExitCode::FAILURE;
+        }
+    };
+    let pages = |manual: &Vec<i32>| {
+        let pages: Vec<String> = manual.iter().map(|p| p.to_string()).collect();
+        pages.join(",")
+    };
+    for (i, manual) in manuals.iter().enumerate() {
+        let broken = day5::violations(manual, &rules);
+        if broken.is_empty() {
+            if all {
+                println!("update {}: {} in order", i + 1, pages(manual));
+            }
+            continue;
+        }
+        println!("update {}: {}", i + 1, pages(manual));
+        for v in &broken {
+            println!(
+                "  breaks {}|{}: {} at position {} comes before {} at position {}",
+                v.before,
+                v.after,
+                v.after,
+                v.at.1 + 1,
+                v.before,
+                v.at.0 + 1
+            );
+        }
+        match day5::fix_manual(manual, &rules) {
+            Ok(fixed) => {
+                println!("  fixed: {}", pages(&fixed));
+                for m in day5::moves(manual, &fixed) {
+                    let place = match m.to {
+                        0 => "first".to_string(),
+                        to => format!("after {}", fixed[to - 1]),
+                    };
+                    println!("  move {} from position {} {place}", m.page, m.from + 1);
+                }
+            }
+            Err(e) => println!("  cannot be fixed: {e}"),
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn verify(day: Option<u8>, slow: bool, manifest: Option<PathBuf>) -> ExitCode {
+    let path = manifest.unwrap_or_else(verify::default_manifest);
+    let cases = match verify::load(&path) {
+        Ok(m) => m.cases,
+        Err(e) => {
+            eprintln!("cannot load {}: {e}", path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+    let dir = path.parent().unwrap_or(&path);
+    let (mut passed, mut failed, mut skipped) = (0, 0, 0);
+    println!(
+        "{:>3}  {:<28} {:>4}  {:>10}  {:<6} details",
+        "day", "input", "part", "time", "result"
+    );
+    for case in cases.iter().filter(|c| day.is_none_or(|d| d == c.day)) {
+        if case.slow && !slow {
+            skipped += 1;
+            continue;
+        }
+        for check in verify::check(dir, case) {
+            let details = match &check.actual {
+                _ if check.passed() => String::new(),
+                Ok(answer) => format!("expected {}, got {answer}", check.expected),
+                Err(e) => e.clone(),
+            };
+            let row = format!(
+                "{:>3}  {:<28} {:>4}  {:>10}  {:<6} {details}",
+                check.day,
+                check.input.display(),
+                check.part,
+                format!("{:.1?}", check.duration),
+                if check.passed() { "ok" } else { "FAIL" },
+            );
+            println!("{}", row.trim_end());
+            if check.passed() {
+                passed += 1;
+            } else {
+                failed += 1;
+            }
+        }
+    }
+    println!("{passed} passed, {failed} failed, {skipped} slow cases skipped");
+    if failed > 0 {
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+        } => match read_input(day, input) {
+            Ok(txt) => run(day, part, &txt, format),
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Bench {
+            day,
+            part,
+            input,
+            runs,
+            format,
+        } => match read_input(day, input) {
+            Ok(txt) => bench(day, part, &txt, runs, format),
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Compare {
+            metric,
+            input,
+            unsigned,
+            format,
+        } => compare(metric, input, unsigned, format),
+        Command::Reports {
+            input,
+            removals,
+            unsafe_only,
+            rules,
+        } => match read_input(2, input) {
+            Ok(txt) => reports(&txt, removals, unsafe_only, rules),
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Scan {
+            input,
+            chunk,
+            explain,
+            highlight,
+        } => scan(input, chunk, explain, highlight),
+        Command::Search {
+            input,
+            words,
+            mask,
+            show,
+            plain,
+            list,
+        } => match read_input(4, input) {
+            Ok(txt) => search(&txt, words, mask, SearchOutput { show, plain, list }),
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Queue { input, all } => match read_input(5, input) {
+            Ok(txt) => queue(&txt, all),
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Generate { day, size, seed } => {
+            match generate::generate(day, size, seed.unwrap_or_else(rand::random)) {
+                Some(txt) => {
+                    println!("{txt}");
+                    ExitCode::SUCCESS
+                }
+                None => {
+                    eprintln!("no generator for day {day}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Verify {
+            day,
+            slow,
+            manifest,
+        } => verify(day, slow, manifest),
+    }
+}
@@ -0,0 +1,134 @@
+use std::{error::Error, fmt, str::FromStr};
+
+// ParseError points to the place of the puzzle input that doesn't look like expected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    // line and column are 1-based
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(day: u8, line: usize, column: usize, expected: &str, found: &str) -> Self {
+        Self {
+            day,
+            line,
+            column,
+            expected: expected.to_string(),
+            found: found.to_string(),
+        }
+    }
+
+    // diagnostic renders the error along with the offending line of the input
+    pub fn diagnostic(&self, txt: &str) -> String {
+        let mut msg = self.to_string();
+        if let Some(line) = txt.lines().nth(self.line.saturating_sub(1)) {
+            let pad = " ".repeat(self.line.to_string().len());
+            msg.push_str(&format!("\n{pad} |\n{} | {line}\n{pad} | ", self.line));
+            msg.push_str(&" ".repeat(self.column.saturating_sub(1)));
+            msg.push('^');
+        }
+        return msg;
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {} input, line {}, column {}: expected {}, found ",
+            self.day, self.line, self.column, self.expected
+        )?;
+        if self.found.is_empty() {
+            write!(f, "nothing")
+        } else {
+            write!(f, "{:?}", self.found)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+// Line is a single line of the puzzle input that remembers its position for error reporting
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub day: u8,
+    pub no: usize,
+    pub text: &'a str,
+}
+
+impl Line<'_> {
+    // column returns 1-based column of the token, which must be a slice of the line text
+    pub fn column(&self, token: &str) -> usize {
+        (token.as_ptr() as usize).saturating_sub(self.text.as_ptr() as usize) + 1
+    }
+
+    // error reports that the token (a slice of the line text) is not what was expected
+    pub fn error(&self, token: &str, expected: &str) -> ParseError {
+        ParseError::new(self.day, self.no, self.column(token), expected, token)
+    }
+
+    // error_at_end reports that the line ended while something else was expected
+    pub fn error_at_end(&self, expected: &str) -> ParseError {
+        ParseError::new(self.day, self.no, self.text.len() + 1, expected, "")
+    }
+
+    pub fn number<T: FromStr>(&self, token: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error(token, "a number"))
+    }
+}
+
+// lines splits the input into numbered lines, trailing empty lines are dropped
+pub fn lines(day: u8, txt: &str) -> impl Iterator<Item = Line<'_>> {
+    txt.trim_end_matches(['\n', '\r'])
+        .lines()
+        .enumerate()
+        .map(move |(i, text)| Line {
+            day,
+            no: i + 1,
+            text,
+        })
+}
+
+// sections splits the input into blocks of lines separated by empty lines
+pub fn sections(day: u8, txt: &str) -> Vec<Vec<Line<'_>>> {
+    let lines: Vec<Line> = lines(day, txt).collect();
+    lines
+        .split(|l| l.text.is_empty())
+        .map(|s| s.to_vec())
+        .collect()
+}
+
+// missing reports that the input ended while something else was expected
+pub fn missing(day: u8, txt: &str, expected: &str) -> ParseError {
+    ParseError::new(day, lines(day, txt).count() + 1, 1, expected, "")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_line() {
+        let l = lines(1, "1 2\n3 x\n\n").nth(1).unwrap();
+        assert_eq!(l.no, 2);
+        assert_eq!(l.number::<i32>(&l.text[..1]), Ok(3));
+        let err = l.number::<i32>(&l.text[2..]).unwrap_err();
+        assert_eq!(err, ParseError::new(1, 2, 3, "a number", "x"));
+        assert_eq!(
+            err.diagnostic("1 2\n3 x\n"),
+            "day 1 input, line 2, column 3: expected a number, found \"x\"\n  |\n2 | 3 x\n  |   ^"
+        );
+    }
+
+    #[test]
+    fn check_sections() {
+        let s = sections(1, "a\nb\n\nc\n");
+        assert_eq!(s.len(), 2);
+        assert_eq!(s[1][0].no, 4);
+        assert_eq!(s[1][0].text, "c");
+    }
+}
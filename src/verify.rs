@@ -0,0 +1,141 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::Deserialize;
+
+use crate::Answer;
+
+// manifest with the known answers, paths of the inputs are relative to it
+pub const MANIFEST: &str = "answers.toml";
+
+// Manifest lists inputs along with their expected answers, a part without an answer is not checked
+#[derive(Debug, Clone, Deserialize)]
+pub struct Manifest {
+    #[serde(rename = "case")]
+    pub cases: Vec<Case>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Case {
+    pub day: u8,
+    pub input: PathBuf,
+    pub part1: Option<Expected>,
+    pub part2: Option<Expected>,
+    // slow cases take minutes and are only checked on request
+    #[serde(default)]
+    pub slow: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Expected {
+    Num(i64),
+    Text(String),
+}
+
+impl From<&Expected> for Answer {
+    fn from(e: &Expected) -> Self {
+        match e {
+            Expected::Num(n) => Answer::Num(*n),
+            Expected::Text(s) => Answer::Text(s.clone()),
+        }
+    }
+}
+
+// Check is the outcome of solving one part of one input, failure to read or parse is kept as text
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub day: u8,
+    pub input: PathBuf,
+    pub part: u8,
+    pub expected: Answer,
+    pub actual: Result<Answer, String>,
+    pub duration: Duration,
+}
+
+impl Check {
+    // passed compares rendered answers, so that e.g. a coordinate matches its "x,y" text
+    pub fn passed(&self) -> bool {
+        match &self.actual {
+            Ok(answer) => answer.to_string() == self.expected.to_string(),
+            Err(_) => false,
+        }
+    }
+}
+
+// default_manifest points to the manifest committed to the repository
+pub fn default_manifest() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(MANIFEST)
+}
+
+pub fn load(path: &Path) -> io::Result<Manifest> {
+    let txt = fs::read_to_string(path)?;
+    toml::from_str(&txt).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))
+}
+
+// check solves every part of the case that has a known answer, each part is parsed separately
+pub fn check(dir: &Path, case: &Case) -> Vec<Check> {
+    let path = dir.join(&case.input);
+    let txt = fs::read_to_string(&path);
+    let mut checks = Vec::new();
+    for (part, expected) in [(1, &case.part1), (2, &case.part2)] {
+        let Some(expected) = expected else {
+            continue;
+        };
+        let (actual, duration) = match &txt {
+            Err(e) => (
+                Err(format!("cannot read {}: {e}", path.display())),
+                Duration::ZERO,
+            ),
+            Ok(txt) => match crate::run(case.day, txt, Some(part)) {
+                None => (
+                    Err(format!("day {} is not solved", case.day)),
+                    Duration::ZERO,
+                ),
+                Some(Err(e)) => (Err(e.to_string()), Duration::ZERO),
+                Some(Ok(solved)) => {
+                    let p = &solved.parts[0];
+                    (Ok(p.answer.clone()), solved.parse + p.duration)
+                }
+            },
+        };
+        checks.push(Check {
+            day: case.day,
+            input: case.input.clone(),
+            part,
+            expected: expected.into(),
+            actual,
+            duration,
+        });
+    }
+    return checks;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_examples() {
+        let path = default_manifest();
+        let manifest = load(&path).unwrap();
+        let dir = path.parent().unwrap();
+        for case in &manifest.cases {
+            let example = case
+                .input
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .starts_with("test_input");
+            if case.slow || !example {
+                continue;
+            }
+            for c in check(dir, case) {
+                assert!(c.passed(), "{c:?}");
+            }
+        }
+    }
+}